@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use common::ParseError;
+
+use crate::table::{self, Table};
+
+const TABLE: Table<4> = Table { fields: ["day", "part", "input-hash", "answer"] };
+/// FNV-1a, so that the hashes in the answers file stay the same across Rust releases.
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3))
+}
+
+/// Known answers keyed by day, part and a hash of the input they were produced from.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: BTreeMap<(u32, usize, u64), String>
+}
+
+impl Answers {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let entries = TABLE.parse(input, |line, [day, part, hash, answer]| {
+            Ok(((line.number(day)?, line.number(part)?, table::parse_hash(line, hash)?), answer.to_owned()))
+        })?;
+
+        Ok(Answers { entries: entries.into_iter().collect() })
+    }
+
+    /// A missing file is treated as having no answers yet.
+    pub fn load(path: &str) -> Result<Self, String> {
+        table::load(path, Answers::parse)
+    }
+
+    fn serialise(&self) -> String {
+        TABLE.serialise(self.entries.iter().map(|((day, part, hash), answer)| {
+            [day.to_string(), part.to_string(), table::format_hash(*hash), answer.clone()]
+        }))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        table::save(path, self.serialise())
+    }
+
+    pub fn get(&self, day: u32, part: usize, hash: u64) -> Option<&str> {
+        self.entries.get(&(day, part, hash)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: usize, hash: u64, answer: String) {
+        self.entries.insert((day, part, hash), answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv_1a() {
+        assert_eq!(hash_input(""), 0xcbf29ce484222325);
+        assert_eq!(hash_input("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(12, 2, 0x1234, "525152".to_owned());
+        answers.insert(2, 1, u64::MAX, "8".to_owned());
+        answers.insert(2, 1, 0x1234, "-3".to_owned());
+
+        let contents = answers.serialise();
+        assert_eq!(contents, "# day part input-hash answer\n\
+            2 1 0000000000001234 -3\n\
+            2 1 ffffffffffffffff 8\n\
+            12 2 0000000000001234 525152\n");
+
+        let parsed = Answers::parse(&contents).unwrap();
+        assert_eq!(parsed.entries, answers.entries);
+        assert_eq!(parsed.get(2, 1, u64::MAX), Some("8"));
+        assert_eq!(parsed.get(2, 2, u64::MAX), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let error = Answers::parse("# comment\n\n2 1 8\n").unwrap_err();
+        assert_eq!(error.line, 3);
+
+        let error = Answers::parse("2 1 0x12 8\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+}
@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, time::{Duration, Instant}};
+
+use common::ParseError;
+
+use crate::table::{self, Table};
+
+const TABLE: Table<4> = Table { fields: ["day", "stage", "input-hash", "median-ns"] };
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+/// Runs `f` `runs` times (at least once) and summarises how long each run took.
+pub fn measure<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Timings {
+    let mut samples: Vec<Duration> = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    samples.sort();
+
+    Timings { min: samples[0], median: samples[samples.len() / 2], max: samples[samples.len() - 1] }
+}
+
+/// Median timings from an earlier run, keyed by day, stage (`parse`, `part1` or `part2`) and a
+/// hash of the input that was timed, so that a different input is never compared to them.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, String, u64), Duration>
+}
+
+impl Baseline {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let medians = TABLE.parse(input, |line, [day, stage, hash, median]| {
+            Ok(((line.number(day)?, stage.to_owned(), table::parse_hash(line, hash)?), Duration::from_nanos(line.number(median)?)))
+        })?;
+
+        Ok(Baseline { medians: medians.into_iter().collect() })
+    }
+
+    /// A missing file is treated as an empty baseline.
+    pub fn load(path: &str) -> Result<Self, String> {
+        table::load(path, Baseline::parse)
+    }
+
+    fn serialise(&self) -> String {
+        TABLE.serialise(self.medians.iter().map(|((day, stage, hash), median)| {
+            [day.to_string(), stage.clone(), table::format_hash(*hash), median.as_nanos().to_string()]
+        }))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        table::save(path, self.serialise())
+    }
+
+    pub fn get(&self, day: u32, stage: &str, hash: u64) -> Option<Duration> {
+        self.medians.get(&(day, stage.to_owned(), hash)).copied()
+    }
+
+    pub fn insert(&mut self, day: u32, stage: &str, hash: u64, median: Duration) {
+        self.medians.insert((day, stage.to_owned(), hash), median);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(3, "part1", 0xabc, Duration::from_nanos(1500));
+        baseline.insert(3, "parse", 0xabc, Duration::from_micros(20));
+        baseline.insert(3, "parse", 0xdef, Duration::from_nanos(7));
+
+        let contents = baseline.serialise();
+        assert_eq!(contents, "# day stage input-hash median-ns\n\
+            3 parse 0000000000000abc 20000\n\
+            3 parse 0000000000000def 7\n\
+            3 part1 0000000000000abc 1500\n");
+
+        let parsed = Baseline::parse(&contents).unwrap();
+        assert_eq!(parsed.medians, baseline.medians);
+        assert_eq!(parsed.get(3, "parse", 0xdef), Some(Duration::from_nanos(7)));
+        assert_eq!(parsed.get(3, "part1", 0xdef), None);
+        assert_eq!(parsed.get(4, "part1", 0xabc), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let error = Baseline::parse("# comment\n\n3 parse 20000\n").unwrap_err();
+        assert_eq!(error.line, 3);
+
+        let error = Baseline::parse("3 parse xyz 20000\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+}
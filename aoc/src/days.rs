@@ -0,0 +1,72 @@
+use common::{render::Colour, Grid, ParseError, SolveError};
+
+pub type Solver = fn(&str) -> Result<String, SolveError>;
+pub type Parser = fn(&str) -> Result<(), ParseError>;
+pub type Renderer = fn(&str) -> Result<Vec<Grid<Colour>>, ParseError>;
+
+// A day has both parts unless its entry names the ones it has, as day 25 does.
+macro_rules! parts {
+    ($krate:ident) => {
+        parts!($krate (solve_part1, solve_part2))
+    };
+    ($krate:ident ($($solver:ident),+)) => {
+        vec![$((|input| $krate::$solver(input).map(|answer| answer.to_string()).map_err(SolveError::from)) as Solver),+]
+    };
+}
+
+macro_rules! solvers {
+    ($($day:literal => $krate:ident $(($($solver:ident),+))?),* $(,)?) => {
+        /// The solver for each part of the day, in order.
+        pub fn get_solvers(day: u32) -> Option<Vec<Solver>> {
+            match day {
+                $($day => Some(parts!($krate $(($($solver),+))?)),)*
+                _ => None
+            }
+        }
+
+        pub fn get_parser(day: u32) -> Option<Parser> {
+            match day {
+                $($day => Some($krate::parse),)*
+                _ => None
+            }
+        }
+    };
+}
+
+solvers! {
+    1 => day_1,
+    2 => day_2,
+    3 => day_3,
+    4 => day_4,
+    5 => day_5,
+    6 => day_6,
+    7 => day_7,
+    8 => day_8,
+    9 => day_9,
+    10 => day_10,
+    11 => day_11,
+    12 => day_12,
+    13 => day_13,
+    14 => day_14,
+    15 => day_15,
+    16 => day_16,
+    17 => day_17,
+    18 => day_18,
+    19 => day_19,
+    20 => day_20,
+    21 => day_21,
+    22 => day_22,
+    23 => day_23,
+    24 => day_24,
+    25 => day_25 (solve_part1),
+}
+
+pub fn get_renderer(day: u32) -> Option<Renderer> {
+    match day {
+        10 => Some(day_10::render),
+        16 => Some(day_16::render),
+        17 => Some(day_17::render),
+        23 => Some(day_23::render),
+        _ => None
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// A flat JSON object, written out on a single line so that a run produces JSON Lines.
+#[derive(Default)]
+pub struct Record {
+    fields: Vec<(&'static str, String)>
+}
+
+impl Record {
+    pub fn string(mut self, key: &'static str, value: &str) -> Self {
+        self.fields.push((key, escape(value)));
+        self
+    }
+
+    pub fn number<T: fmt::Display>(mut self, key: &'static str, value: T) -> Self {
+        self.fields.push((key, value.to_string()));
+        self
+    }
+
+    pub fn null(mut self, key: &'static str) -> Self {
+        self.fields.push((key, "null".to_owned()));
+        self
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (idx, (key, value)) in self.fields.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", escape(key), value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if (c as u32) < 0x20 => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c)
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(escape("plain"), "\"plain\"");
+        assert_eq!(escape("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(escape("C:\\aoc\\"), "\"C:\\\\aoc\\\\\"");
+        assert_eq!(escape("a\nb\r\tc"), "\"a\\nb\\r\\tc\"");
+        assert_eq!(escape("\u{0}\u{1b}\u{1f} \u{7f}é"), "\"\\u0000\\u001b\\u001f \u{7f}é\"");
+    }
+
+    #[test]
+    fn record() {
+        assert_eq!(Record::default().to_string(), "{}");
+
+        let record = Record::default().number("day", 3).string("answer", "a\"b").null("error");
+        assert_eq!(record.to_string(), "{\"day\":3,\"answer\":\"a\\\"b\",\"error\":null}");
+    }
+}
@@ -0,0 +1,459 @@
+mod answers;
+mod bench;
+mod days;
+mod json;
+mod table;
+use std::{env, fs, process::ExitCode, time::{Duration, Instant}};
+
+use answers::Answers;
+use bench::Baseline;
+use common::{render::Format, SolveError};
+
+const USAGE: &str = "usage: aoc run <day>|all [--part 1|2] [--input <path>|-] [--json]
+       aoc verify [<day>...] [--answers <path>] [--record]
+       aoc bench [<day>...] [--runs <n>] [--baseline <path>] [--threshold <percent>] [--save]
+       aoc batch <day> <directory>
+       aoc render <day> [--input <path>|-] [--format ppm|svg] [--scale <n>] [--output <directory>]";
+
+struct RunArgs {
+    days: Vec<u32>,
+    parts: Vec<usize>,
+    input: Option<String>,
+    json: bool
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut args = args.iter();
+    let days = match args.next().map(String::as_str) {
+        Some("all") => all_days(),
+        Some(day) => vec![day.parse().map_err(|_| format!("invalid day '{}'", day))?],
+        None => return Err(USAGE.to_owned())
+    };
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => match args.next() {
+                Some(part) if part == "1" || part == "2" => { parts = vec![part.parse().unwrap()]; },
+                Some(part) => return Err(format!("invalid part '{}'", part)),
+                None => return Err(USAGE.to_owned())
+            },
+            "--input" => { input = Some(args.next().ok_or(USAGE)?.to_owned()); },
+            "--json" => { json = true; },
+            _ => return Err(USAGE.to_owned())
+        }
+    }
+
+    if input.is_some() && days.len() > 1 {
+        return Err("--input needs a single day".to_owned());
+    }
+
+    Ok(RunArgs { days, parts, input, json })
+}
+
+struct VerifyArgs {
+    days: Vec<u32>,
+    answers: String,
+    record: bool
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut answers = "answers.txt".to_owned();
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => { answers = args.next().ok_or(USAGE)?.to_owned(); },
+            "--record" => { record = true; },
+            day => { days.push(day.parse().map_err(|_| format!("invalid day '{}'", day))?); }
+        }
+    }
+
+    if days.is_empty() {
+        days = all_days();
+    }
+
+    Ok(VerifyArgs { days, answers, record })
+}
+
+struct BenchArgs {
+    days: Vec<u32>,
+    runs: usize,
+    baseline: String,
+    threshold: u32,
+    save: bool
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut runs = 10;
+    let mut baseline = "bench_baseline.txt".to_owned();
+    let mut threshold = 20;
+    let mut save = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let value = args.next().ok_or(USAGE)?;
+                runs = value.parse().map_err(|_| format!("invalid run count '{}'", value))?;
+            },
+            "--baseline" => { baseline = args.next().ok_or(USAGE)?.to_owned(); },
+            "--threshold" => {
+                let value = args.next().ok_or(USAGE)?;
+                threshold = value.parse().map_err(|_| format!("invalid threshold '{}'", value))?;
+            },
+            "--save" => { save = true; },
+            day => { days.push(day.parse().map_err(|_| format!("invalid day '{}'", day))?); }
+        }
+    }
+
+    if days.is_empty() {
+        days = all_days();
+    }
+
+    Ok(BenchArgs { days, runs, baseline, threshold, save })
+}
+
+struct BatchArgs {
+    day: u32,
+    directory: String
+}
+
+fn parse_batch_args(args: &[String]) -> Result<BatchArgs, String> {
+    let [day, directory] = args else {
+        return Err(USAGE.to_owned());
+    };
+
+    Ok(BatchArgs { day: day.parse().map_err(|_| format!("invalid day '{}'", day))?, directory: directory.to_owned() })
+}
+
+struct RenderArgs {
+    day: u32,
+    input: Option<String>,
+    format: Format,
+    scale: usize,
+    output: String
+}
+
+fn parse_render_args(args: &[String]) -> Result<RenderArgs, String> {
+    let mut args = args.iter();
+    let day = args.next().ok_or(USAGE)?;
+    let day = day.parse().map_err(|_| format!("invalid day '{}'", day))?;
+    let mut input = None;
+    let mut format = Format::Ppm;
+    let mut scale = 4;
+    let mut output = "render".to_owned();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => { input = Some(args.next().ok_or(USAGE)?.to_owned()); },
+            "--format" => {
+                let value = args.next().ok_or(USAGE)?;
+                format = Format::parse(value).ok_or(format!("invalid format '{}'", value))?;
+            },
+            "--scale" => {
+                let value = args.next().ok_or(USAGE)?;
+                scale = value.parse().ok().filter(|s| *s > 0).ok_or(format!("invalid scale '{}'", value))?;
+            },
+            "--output" => { output = args.next().ok_or(USAGE)?.to_owned(); },
+            _ => return Err(USAGE.to_owned())
+        }
+    }
+
+    Ok(RenderArgs { day, input, format, scale, output })
+}
+
+fn all_days() -> Vec<u32> {
+    (1..=25).filter(|day| days::get_solvers(*day).is_some()).collect()
+}
+
+/// Where `read_input` will read from: a path, or `-` for stdin.
+fn input_name(day: u32, input: &Option<String>) -> String {
+    match input {
+        Some(path) => path.to_owned(),
+        None => format!("day_{}/data.txt", day)
+    }
+}
+
+fn read_input(day: u32, input: &Option<String>) -> Result<String, String> {
+    common::read_input(&input_name(day, input))
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    for day in &args.days {
+        let solvers = days::get_solvers(*day).ok_or(format!("no solver for day {}", day))?;
+        let input = read_input(*day, &args.input)?;
+
+        for part in &args.parts {
+            let Some(solver) = solvers.get(part - 1) else {
+                continue;
+            };
+            let answer = solver(&input).map_err(|e| format!("day {} part {}: {}", day, part, e))?;
+            println!("Day {} part {}: {}", day, part, answer);
+        }
+    }
+
+    Ok(())
+}
+
+/// Like `run`, but prints one JSON record per day and part. Parse errors and overflows are
+/// reported in the record rather than stopping the run, though the exit status still reflects them.
+/// The solvers take the raw input, so `total_ns` covers parsing it again as well as solving, while
+/// `parse_ns` is the parse on its own.
+fn run_json(args: &RunArgs) -> Result<(), String> {
+    let mut failures = 0;
+
+    for day in &args.days {
+        let solvers = days::get_solvers(*day).ok_or(format!("no solver for day {}", day))?;
+        let parser = days::get_parser(*day).ok_or(format!("no parser for day {}", day))?;
+        let input = read_input(*day, &args.input)?;
+
+        let start = Instant::now();
+        let parsed = parser(&input);
+        let parse_ns = start.elapsed().as_nanos();
+
+        for part in &args.parts {
+            let Some(solver) = solvers.get(part - 1) else {
+                continue;
+            };
+            let start = Instant::now();
+            let answer = parsed.clone().map_err(SolveError::from).and_then(|_| solver(&input));
+            let total_ns = start.elapsed().as_nanos();
+
+            let record = json::Record::default()
+                .number("day", day)
+                .number("part", part)
+                .string("input", &input_name(*day, &args.input))
+                .string("input_hash", &table::format_hash(answers::hash_input(&input)))
+                .number("parse_ns", parse_ns)
+                .number("total_ns", total_ns);
+
+            let record = match answer {
+                Ok(answer) => record.string("answer", &answer).null("error"),
+                Err(e) => {
+                    failures += 1;
+                    record.null("answer").string("error", &e.to_string())
+                }
+            };
+            println!("{}", record);
+        }
+    }
+
+    if failures > 0 {
+        Err(format!("{} part(s) failed", failures))
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs every part of every requested day against `day_<n>/data.txt` and compares the results with
+/// the recorded answers for that input. With `record`, answers that aren't known yet are saved.
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let mut answers = Answers::load(&args.answers)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in &args.days {
+        let solvers = days::get_solvers(*day).ok_or(format!("no solver for day {}", day))?;
+        let input = read_input(*day, &None)?;
+        let hash = answers::hash_input(&input);
+
+        for (idx, solver) in solvers.iter().enumerate() {
+            let part = idx + 1;
+            let actual = solver(&input).unwrap_or_else(|e| format!("error: {}", e));
+
+            match answers.get(*day, part, hash) {
+                Some(expected) if expected == actual => {
+                    println!("Day {} part {}: pass ({})", day, part, actual);
+                    passed += 1;
+                },
+                Some(expected) => {
+                    println!("Day {} part {}: FAIL (expected {}, got {})", day, part, expected, actual);
+                    failed += 1;
+                },
+                None => {
+                    println!("Day {} part {}: missing (got {})", day, part, actual);
+                    missing += 1;
+                    if args.record && !actual.starts_with("error: ") {
+                        answers.insert(*day, part, hash, actual);
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if args.record && missing > 0 {
+        answers.save(&args.answers)?;
+    }
+
+    if failed > 0 {
+        Err(format!("{} answer(s) changed", failed))
+    } else {
+        Ok(())
+    }
+}
+
+/// Times parsing and each part of every requested day over `runs` runs and compares the medians
+/// with the baseline, flagging any stage that got more than `threshold` percent slower. With
+/// `save`, the new medians become the baseline.
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let mut baseline = Baseline::load(&args.baseline)?;
+    let mut regressions = 0;
+
+    for day in &args.days {
+        let solvers = days::get_solvers(*day).ok_or(format!("no solver for day {}", day))?;
+        let parser = days::get_parser(*day).ok_or(format!("no parser for day {}", day))?;
+        let input = read_input(*day, &None)?;
+        let hash = answers::hash_input(&input);
+
+        parser(&input).map_err(|e| format!("day {}: {}", day, e))?;
+
+        let mut stages = vec![("parse".to_owned(), bench::measure(args.runs, || parser(&input)))];
+        for (idx, solver) in solvers.iter().enumerate() {
+            stages.push((format!("part{}", idx + 1), bench::measure(args.runs, || solver(&input))));
+        }
+
+        for (stage, timings) in stages {
+            print!("Day {:>2} {}: min {:>10.1?}  median {:>10.1?}  max {:>10.1?}", day, stage, timings.min, timings.median, timings.max);
+
+            if let Some(previous) = baseline.get(*day, &stage, hash) {
+                let change = (timings.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                print!("  (baseline {:.1?}, {:+.0}%)", previous, change);
+                if change > f64::from(args.threshold) {
+                    print!(" REGRESSION");
+                    regressions += 1;
+                }
+            }
+            println!();
+
+            if args.save {
+                baseline.insert(*day, &stage, hash, timings.median);
+            }
+        }
+    }
+
+    if args.save {
+        baseline.save(&args.baseline)?;
+    }
+
+    if regressions > 0 {
+        Err(format!("{} stage(s) more than {}% slower than the baseline", regressions, args.threshold))
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs every part of one day over every file in a directory and prints a table of the answers
+/// and how long each part took. A file that fails to read or parse gets an error in its row
+/// rather than stopping the batch.
+fn batch(args: &BatchArgs) -> Result<(), String> {
+    let solvers = days::get_solvers(args.day).ok_or(format!("no solver for day {}", args.day))?;
+
+    let entries = fs::read_dir(&args.directory).map_err(|e| format!("failed to read {}: {}", args.directory, e))?;
+    let mut paths: Vec<_> = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("failed to read {}: {}", args.directory, e))?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+
+    let mut header = vec!["file".to_owned()];
+    for part in 1..=solvers.len() {
+        header.extend([format!("part {}", part), "time".to_owned()]);
+    }
+    let columns = header.len();
+    let mut rows = vec![header];
+    let mut total = Duration::ZERO;
+    let mut failures = 0;
+
+    for path in &paths {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let input = match common::read_input(&path.to_string_lossy()) {
+            Ok(input) => input,
+            Err(message) => {
+                failures += 1;
+                let mut row = vec![name, message];
+                row.resize(columns, String::new());
+                rows.push(row);
+                continue;
+            }
+        };
+
+        let mut cells = vec![name];
+        for solver in &solvers {
+            let start = Instant::now();
+            let answer = solver(&input);
+            let elapsed = start.elapsed();
+            total += elapsed;
+
+            cells.push(answer.unwrap_or_else(|e| {
+                failures += 1;
+                format!("error: {}", e)
+            }));
+            cells.push(format!("{:.1?}", elapsed));
+        }
+        rows.push(cells);
+    }
+
+    let widths: Vec<usize> = (0..columns).map(|col| rows.iter().map(|row| row[col].len()).max().unwrap()).collect();
+    for row in &rows {
+        let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, width)| format!("{:<1$}", cell, width)).collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+    println!("{} file(s) in {:.1?}, {} failure(s)", paths.len(), total, failures);
+
+    if failures > 0 {
+        Err(format!("{} part(s) or file(s) failed", failures))
+    } else {
+        Ok(())
+    }
+}
+
+/// Renders a day's input into the output directory, as `day_<n>.<ext>` for a single image or
+/// numbered `day_<n>_<frame>.<ext>` files for a sequence.
+fn render(args: &RenderArgs) -> Result<(), String> {
+    let renderer = days::get_renderer(args.day).ok_or(format!("no renderer for day {}", args.day))?;
+    let input = read_input(args.day, &args.input)?;
+    let frames = renderer(&input).map_err(|e| format!("day {}: {}", args.day, e))?;
+
+    fs::create_dir_all(&args.output).map_err(|e| format!("failed to create {}: {}", args.output, e))?;
+    for (idx, frame) in frames.iter().enumerate() {
+        let name = if frames.len() == 1 {
+            format!("day_{}.{}", args.day, args.format.extension())
+        } else {
+            format!("day_{}_{:04}.{}", args.day, idx, args.format.extension())
+        };
+        let path = format!("{}/{}", args.output, name);
+        fs::write(&path, args.format.encode(frame, args.scale)).map_err(|e| format!("failed to write {}: {}", path, e))?;
+    }
+    println!("Wrote {} frame(s) to {}", frames.len(), args.output);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).and_then(|a| if a.json { run_json(&a) } else { run(&a) }),
+        Some("verify") => parse_verify_args(&args[1..]).and_then(|a| verify(&a)),
+        Some("bench") => parse_bench_args(&args[1..]).and_then(|a| bench(&a)),
+        Some("batch") => parse_batch_args(&args[1..]).and_then(|a| batch(&a)),
+        Some("render") => parse_render_args(&args[1..]).and_then(|a| render(&a)),
+        _ => Err(USAGE.to_owned())
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
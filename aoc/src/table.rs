@@ -0,0 +1,91 @@
+use std::{fs::{read_to_string, write}, io::ErrorKind};
+
+use common::{Line, ParseError};
+
+/// The layout of the files kept between runs: a `#` header naming the fields, then one entry per
+/// line with its fields separated by whitespace. Blank lines and other `#` comments are skipped.
+pub struct Table<const N: usize> {
+    pub fields: [&'static str; N]
+}
+
+impl<const N: usize> Table<N> {
+    /// Parses every entry with `entry`, which is given the entry's line and its fields.
+    pub fn parse<'a, T, F>(&self, input: &'a str, mut entry: F) -> Result<Vec<T>, ParseError>
+    where F: FnMut(Line<'a>, [&'a str; N]) -> Result<T, ParseError> {
+        let mut entries = Vec::new();
+
+        for line in common::lines(input) {
+            if line.text.is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+
+            let Ok(fields) = line.text.split_whitespace().collect::<Vec<_>>().try_into() else {
+                let expected: Vec<String> = self.fields.iter().map(|field| format!("<{}>", field)).collect();
+                return Err(line.error(line.text, &format!("'{}'", expected.join(" "))));
+            };
+            entries.push(entry(line, fields)?);
+        }
+
+        Ok(entries)
+    }
+
+    pub fn serialise(&self, entries: impl IntoIterator<Item = [String; N]>) -> String {
+        let mut contents = format!("# {}\n", self.fields.join(" "));
+        for entry in entries {
+            contents += &format!("{}\n", entry.join(" "));
+        }
+
+        contents
+    }
+}
+
+/// Reads `path` with `parse`, treating a missing file as having no entries yet.
+pub fn load<T: Default>(path: &str, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> Result<T, String> {
+    match read_to_string(path) {
+        Ok(contents) => parse(&contents).map_err(|e| format!("{}: {}", path, e)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(format!("failed to read {}: {}", path, e))
+    }
+}
+
+pub fn save(path: &str, contents: String) -> Result<(), String> {
+    write(path, contents).map_err(|e| format!("failed to write {}: {}", path, e))
+}
+
+pub fn parse_hash(line: Line, hash: &str) -> Result<u64, ParseError> {
+    u64::from_str_radix(hash, 16).map_err(|_| line.error(hash, "a hexadecimal hash"))
+}
+
+pub fn format_hash(hash: u64) -> String {
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: Table<2> = Table { fields: ["name", "count"] };
+
+    #[test]
+    fn round_trip() {
+        let contents = TABLE.serialise([["a".to_owned(), "1".to_owned()], ["b".to_owned(), "22".to_owned()]]);
+        assert_eq!(contents, "# name count\na 1\nb 22\n");
+
+        let commented = format!("{}\n# a comment\n", contents);
+        let entries = TABLE.parse(&commented, |line, [name, count]| {
+            Ok((name, line.number::<u32>(count)?))
+        });
+        assert_eq!(entries, Ok(vec![("a", 1), ("b", 22)]));
+    }
+
+    #[test]
+    fn rejects_the_wrong_number_of_fields() {
+        let error = TABLE.parse("a 1\nb 2 3\n", |_, fields| Ok(fields)).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "'<name> <count>'"));
+    }
+
+    #[test]
+    fn missing_file_has_no_entries() {
+        assert_eq!(load("no/such/file.txt", |_| Ok(vec![1])), Ok(Vec::new()));
+    }
+}
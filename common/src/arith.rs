@@ -0,0 +1,180 @@
+use std::{error::Error, fmt, ops::{Add, Mul, Sub}};
+
+use crate::ParseError;
+
+/// The type answers are accumulated in: `i64`, or `i128` with the `wide` feature.
+#[cfg(not(feature = "wide"))]
+pub type Int = i64;
+#[cfg(feature = "wide")]
+pub type Int = i128;
+
+/// The unsigned counterpart of `Int`: `u64`, or `u128` with the `wide` feature.
+#[cfg(not(feature = "wide"))]
+pub type UInt = u64;
+#[cfg(feature = "wide")]
+pub type UInt = u128;
+
+/// Where in a solver something went wrong, so that an error can say which day and step it came
+/// from. Made with `step!`, which fills in the day from the calling crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub day: &'static str,
+    pub name: &'static str
+}
+
+#[macro_export]
+macro_rules! step {
+    ($name:expr) => {
+        $crate::arith::Step { day: env!("CARGO_PKG_NAME"), name: $name }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow(pub Step);
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: overflow in {}", self.0.day, self.0.name)
+    }
+}
+
+impl Error for Overflow {}
+
+/// Anything that can stop a solver: input it can't parse, an answer too big for its type, or
+/// input that parses but has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow),
+    NoSolution(Step)
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => e.fmt(f),
+            SolveError::Overflow(e) => e.fmt(f),
+            SolveError::NoSolution(step) => write!(f, "{}: no solution, {}", step.day, step.name)
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(e: Overflow) -> Self {
+        SolveError::Overflow(e)
+    }
+}
+
+/// The integer types answers are built from.
+pub trait Accumulate: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn try_add(self, other: Self) -> Option<Self>;
+    fn try_sub(self, other: Self) -> Option<Self>;
+    fn try_mul(self, other: Self) -> Option<Self>;
+    fn try_pow(self, exponent: u32) -> Option<Self>;
+}
+
+macro_rules! accumulate {
+    ($($t:ty),*) => {$(
+        impl Accumulate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn try_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+
+            fn try_sub(self, other: Self) -> Option<Self> {
+                self.checked_sub(other)
+            }
+
+            fn try_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+
+            fn try_pow(self, exponent: u32) -> Option<Self> {
+                self.checked_pow(exponent)
+            }
+        }
+    )*};
+}
+
+accumulate!(i32, i64, i128, u32, u64, u128, usize);
+
+// Without the `checked` feature these are the plain operators, which panic on overflow in debug
+// builds and wrap in release builds.
+fn checked<T>(result: Option<T>, unchecked: impl FnOnce() -> T, step: Step) -> Result<T, Overflow> {
+    if cfg!(feature = "checked") {
+        result.ok_or(Overflow(step))
+    } else {
+        Ok(unchecked())
+    }
+}
+
+pub fn add<T: Accumulate>(a: T, b: T, step: Step) -> Result<T, Overflow> {
+    checked(a.try_add(b), || a + b, step)
+}
+
+pub fn sub<T: Accumulate>(a: T, b: T, step: Step) -> Result<T, Overflow> {
+    checked(a.try_sub(b), || a - b, step)
+}
+
+pub fn mul<T: Accumulate>(a: T, b: T, step: Step) -> Result<T, Overflow> {
+    checked(a.try_mul(b), || a * b, step)
+}
+
+pub fn pow<T: Accumulate>(base: T, exponent: u32, step: Step) -> Result<T, Overflow> {
+    checked(base.try_pow(exponent), || (0..exponent).fold(T::ONE, |power, _| power * base), step)
+}
+
+pub fn sum<T: Accumulate>(values: impl IntoIterator<Item = T>, step: Step) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ZERO, |total, value| add(total, value, step))
+}
+
+pub fn product<T: Accumulate>(values: impl IntoIterator<Item = T>, step: Step) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ONE, |total, value| mul(total, value, step))
+}
+
+/// Converts into a type that can hold every value of `T`, such as an `i64` into `Int`. Unlike
+/// `Int::from` this is fine when the two are the same type, as they are without `wide`.
+pub fn widen<T, U: From<T>>(value: T) -> U {
+    U::from(value)
+}
+
+/// Converts between integer types, which is always checked since a value that doesn't fit can't
+/// be wrapped into one that does.
+pub fn convert<T, U: TryFrom<T>>(value: T, step: Step) -> Result<U, Overflow> {
+    U::try_from(value).map_err(|_| Overflow(step))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulates_like_the_operators() {
+        assert_eq!(sum([1, 2, 3_i32], step!("a sum")), Ok(6));
+        assert_eq!(product([2, 3, 4_u64], step!("a product")), Ok(24));
+        assert_eq!(pow(2_i64, 10, step!("a power")), Ok(1024));
+        assert_eq!(convert::<usize, Int>(7, step!("a conversion")), Ok(7));
+        assert_eq!(convert::<i64, u32>(-1, step!("a conversion")), Err(Overflow(step!("a conversion"))));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflow_names_the_day_and_step() {
+        let overflow = sum([i32::MAX, 1], step!("a sum")).unwrap_err();
+        assert_eq!(overflow.to_string(), "common: overflow in a sum");
+        assert_eq!(mul(u32::MAX, 2, step!("a product")), Err(Overflow(step!("a product"))));
+    }
+}
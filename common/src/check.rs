@@ -0,0 +1,52 @@
+use std::fmt::Debug;
+
+use crate::Rng;
+
+/// Generates `cases` random inputs and checks that `optimised` and `naive` agree on each of them.
+/// On a disagreement the input is shrunk by repeatedly taking the first candidate from `shrink`
+/// that still disagrees, and the smallest failing input found is reported in the panic.
+pub fn differential<T, R, G, S, O, N>(seed: u64, cases: usize, generate: G, shrink: S, optimised: O, naive: N)
+where
+    T: Debug,
+    R: PartialEq + Debug,
+    G: Fn(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+    O: Fn(&T) -> R,
+    N: Fn(&T) -> R
+{
+    let mut rng = Rng::new(seed);
+    let disagrees = |case: &T| optimised(case) != naive(case);
+
+    for _ in 0..cases {
+        let case = generate(&mut rng);
+        if !disagrees(&case) {
+            continue;
+        }
+
+        let mut smallest = case;
+        while let Some(smaller) = shrink(&smallest).into_iter().find(|c| disagrees(c)) {
+            smallest = smaller;
+        }
+
+        panic!(
+            "optimised and naive solutions disagree on {:#?}: {:?} vs {:?}",
+            smallest, optimised(&smallest), naive(&smallest)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn agreeing_solvers_pass() {
+        differential(1, 100, |rng| rng.range(0..100), |_| Vec::new(), |n| n * 2, |n| n + n);
+    }
+
+    #[test]
+    #[should_panic(expected = "optimised and naive solutions disagree on 10: true vs false")]
+    fn disagreement_is_shrunk() {
+        differential(1, 100, |rng| rng.range(10..1000), |n| vec![n / 2, n - 1], |n| *n >= 10, |_| false);
+    }
+}
@@ -0,0 +1,206 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::arith::{self, Accumulate, Overflow, Step};
+/// Where a sequence of states starts to repeat, and how many steps each repeat takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    pub start: usize,
+    pub length: usize
+}
+
+impl Period {
+    /// Finds the period of the states stepped to from `initial` with Brent's algorithm, which only
+    /// holds a couple of states at a time. The sequence is walked more than once, so `step` has to
+    /// depend only on its argument, and it never returns if the states don't repeat.
+    pub fn brent<T: Clone + Eq, F: Fn(&T) -> T>(initial: &T, step: F) -> Self {
+        // The tortoise waits at each power of two for the hare to catch it up, which it does once
+        // the power reaches the length of the cycle.
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = initial.clone();
+        let mut hare = step(initial);
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            hare = step(&hare);
+            length += 1;
+        }
+
+        // With the hare a whole cycle ahead, the two first meet where the cycle starts.
+        let mut tortoise = initial.clone();
+        let mut hare = (0..length).fold(initial.clone(), |state, _| step(&state));
+        let mut start = 0;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            start += 1;
+        }
+
+        Period { start, length }
+    }
+
+    /// The fewest steps that reach the same state as `steps` steps do.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// The states a simulation passes through, recorded until one repeats. Unlike `Period::brent`
+/// every state is kept, so `step` is only ever called once per step (it may advance a simulation
+/// of its own rather than work from its argument) and any step can be looked up directly once the
+/// cycle is known.
+pub struct Cycle<T> {
+    states: Vec<T>,
+    start: Option<usize>
+}
+
+impl<T: Clone + Eq + Hash> Cycle<T> {
+    /// Steps from `initial` until a state repeats or `max_steps` steps have been taken.
+    pub fn find<F: FnMut(&T) -> T>(initial: T, max_steps: usize, mut step: F) -> Self {
+        let mut seen = HashMap::from([(initial.clone(), 0)]);
+        let mut states = vec![initial];
+
+        while states.len() <= max_steps {
+            let next = step(&states[states.len() - 1]);
+            if let Some(start) = seen.get(&next) {
+                return Cycle { states, start: Some(*start) };
+            }
+            seen.insert(next.clone(), states.len());
+            states.push(next);
+        }
+
+        Cycle { states, start: None }
+    }
+}
+
+impl<T> Cycle<T> {
+    /// How many steps it takes to first reach a state on the cycle, if a repeat was seen.
+    pub fn start(&self) -> Option<usize> {
+        self.start
+    }
+
+    pub fn period(&self) -> Option<usize> {
+        self.start.map(|start| self.states.len() - start)
+    }
+
+    /// Which recorded state is the same as the one after `steps` steps.
+    fn index(&self, steps: usize) -> Option<usize> {
+        match self.start {
+            Some(start) => Some(Period { start, length: self.states.len() - start }.reduce(steps)),
+            None => (steps < self.states.len()).then_some(steps)
+        }
+    }
+
+    /// The state after `steps` steps, or `None` if that's past what was recorded and no repeat
+    /// was seen.
+    pub fn state_at(&self, steps: usize) -> Option<&T> {
+        self.index(steps).map(|idx| &self.states[idx])
+    }
+
+    /// The total of `metric` over the states after each of the first `steps` steps (not counting
+    /// the initial state), with whole periods of the cycle counted in one go. `None` if that's past
+    /// what was recorded and no repeat was seen.
+    pub fn sum_to<U, F>(&self, steps: usize, metric: F, step: Step) -> Result<Option<U>, Overflow>
+    where U: Accumulate + TryFrom<usize>, F: Fn(&T) -> U {
+        let sum = |states: &[T]| arith::sum(states.iter().map(&metric), step);
+
+        match self.start {
+            Some(start) if steps >= self.states.len() => {
+                let cycle = &self.states[start..];
+                let first = start.max(1);
+                let count = steps - first + 1;
+                let offset = first - start;
+
+                let before = sum(&self.states[1..first])?;
+                let periods = arith::mul(sum(cycle)?, arith::convert(count / cycle.len(), step)?, step)?;
+                let remainder = arith::sum((0..count % cycle.len()).map(|idx| metric(&cycle[(offset + idx) % cycle.len()])), step)?;
+
+                Ok(Some(arith::sum([before, periods, remainder], step)?))
+            },
+            _ if steps < self.states.len() => sum(&self.states[1..steps + 1]).map(Some),
+            _ => Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUM: Step = crate::step!("a sum of states");
+
+    fn state(state: &usize) -> i64 {
+        *state as i64
+    }
+
+    // 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, ...: a tail of two steps and then a cycle of four.
+    fn with_tail(state: &usize) -> usize {
+        if *state == 5 { 2 } else { state + 1 }
+    }
+
+    // 0, 1, 2, 0, 1, 2, ...
+    fn pure_cycle(state: &usize) -> usize {
+        (state + 1) % 3
+    }
+
+    #[test]
+    fn finds_a_pure_cycle() {
+        let cycle = Cycle::find(0, usize::MAX, pure_cycle);
+        assert_eq!((cycle.start(), cycle.period()), (Some(0), Some(3)));
+        assert_eq!(cycle.state_at(0), Some(&0));
+        assert_eq!(cycle.state_at(3), Some(&0));
+        assert_eq!(cycle.state_at(1_000_000_001), Some(&(1_000_000_001 % 3)));
+        assert_eq!(cycle.sum_to(0, state, SUM), Ok(Some(0)));
+        assert_eq!(cycle.sum_to(7, state, SUM), Ok(Some(7)));
+        assert_eq!(Period::brent(&0, pure_cycle), Period { start: 0, length: 3 });
+    }
+
+    #[test]
+    fn finds_a_cycle_with_a_tail() {
+        let cycle = Cycle::find(0, usize::MAX, with_tail);
+        assert_eq!((cycle.start(), cycle.period()), (Some(2), Some(4)));
+        assert_eq!(Period::brent(&0, with_tail), Period { start: 2, length: 4 });
+
+        // Step 2 is where the cycle starts and step 6 where it first comes round again.
+        let walked = |steps: usize| (0..steps).fold(0, |state, _| with_tail(&state));
+        for steps in [0, 1, 2, 5, 6, 7, 100] {
+            let total = (1..=steps).map(|step| walked(step) as i64).sum::<i64>();
+            assert_eq!(cycle.state_at(steps), Some(&walked(steps)), "state after {} steps", steps);
+            assert_eq!(cycle.sum_to(steps, state, SUM), Ok(Some(total)), "sum to {} steps", steps);
+        }
+        assert_eq!(cycle.state_at(1_000_000), Some(&4));
+        // The first five steps add 1 + 2 + 3 + 4 + 5, and every four after them 2 + 3 + 4 + 5.
+        assert_eq!(cycle.sum_to(1_000_005, state, SUM), Ok(Some(15 + 14 * 250_000)));
+    }
+
+    #[test]
+    fn reduces_steps_onto_the_cycle() {
+        let period = Period { start: 2, length: 4 };
+        assert_eq!([0, 1, 2, 5, 6, 9, 1_000_000].map(|steps| period.reduce(steps)), [0, 1, 2, 5, 2, 5, 4]);
+    }
+
+    #[test]
+    fn stops_at_the_limit_without_a_repeat() {
+        let cycle = Cycle::find(0, 5, |state: &usize| state + 1);
+        assert_eq!(cycle.start(), None);
+        assert_eq!(cycle.state_at(5), Some(&5));
+        assert_eq!(cycle.state_at(6), None);
+        assert_eq!(cycle.sum_to(5, state, SUM), Ok(Some(15)));
+        assert_eq!(cycle.sum_to(6, state, SUM), Ok(None));
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn sum_overflow() {
+        let cycle = Cycle::find(0, usize::MAX, pure_cycle);
+        assert_eq!(cycle.sum_to(usize::MAX, |state| *state as u32, SUM), Err(Overflow(SUM)));
+        assert_eq!(cycle.sum_to(usize::MAX, |state| *state as u128, SUM), Ok(Some(usize::MAX as u128)));
+    }
+}
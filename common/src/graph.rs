@@ -0,0 +1,387 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash, ops::Add};
+
+/// The cost of reaching every node a search got to, along with the node it was reached from so
+/// that the path there can be rebuilt.
+pub struct Paths<N, C> {
+    costs: HashMap<N, C>,
+    previous: HashMap<N, N>
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn into_costs(self) -> HashMap<N, C> {
+        self.costs
+    }
+
+    /// The nodes from a start up to and including `node`.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+
+        Some(rebuild(&self.previous, node.clone()))
+    }
+}
+
+fn rebuild<N: Clone + Eq + Hash>(previous: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(node) = previous.get(&path[path.len() - 1]) {
+        path.push(node.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+/// Breadth first search out from `starts`, where every step costs one.
+pub fn bfs<N, I, F>(starts: impl IntoIterator<Item = N>, mut neighbours: F) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I
+{
+    let mut costs = HashMap::new();
+    let mut previous = HashMap::new();
+    let mut to_do = VecDeque::new();
+    for start in starts {
+        costs.insert(start.clone(), 0);
+        to_do.push_back(start);
+    }
+
+    while let Some(node) = to_do.pop_front() {
+        let cost = costs[&node];
+        for next in neighbours(&node) {
+            if !costs.contains_key(&next) {
+                costs.insert(next.clone(), cost + 1);
+                previous.insert(next.clone(), node.clone());
+                to_do.push_back(next);
+            }
+        }
+    }
+
+    Paths { costs, previous }
+}
+
+/// A node waiting in the queue. Ordered so that the lowest `priority` comes out of a `BinaryHeap`
+/// first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// The cheapest path from any of `starts` to a node satisfying `is_goal`, and its cost. The
+/// `heuristic` must never overestimate the remaining cost; `C::default()` is taken as zero.
+pub fn a_star<N, C, I, F, H, G>(starts: impl IntoIterator<Item = N>, mut neighbours: F, mut heuristic: H, mut is_goal: G) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool
+{
+    let mut costs = HashMap::new();
+    let mut previous = HashMap::new();
+    let mut to_do = BinaryHeap::new();
+    for start in starts {
+        costs.insert(start.clone(), C::default());
+        to_do.push(Queued { priority: heuristic(&start), cost: C::default(), node: start });
+    }
+
+    while let Some(Queued { cost, node, .. }) = to_do.pop() {
+        if cost > costs[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((cost, rebuild(&previous, node)));
+        }
+
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|c| next_cost < *c) {
+                costs.insert(next.clone(), next_cost);
+                previous.insert(next.clone(), node.clone());
+                to_do.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, node: next });
+            }
+        }
+    }
+
+    None
+}
+
+/// The cheapest path from any of `starts` to a node satisfying `is_goal`, and its cost.
+pub fn dijkstra<N, C, I, F, G>(starts: impl IntoIterator<Item = N>, neighbours: F, is_goal: G) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool
+{
+    a_star(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Every node reachable from `starts`, each one before all of the nodes it leads to, or `None` if
+/// there's a cycle among them.
+pub fn topological_order<N, I, F>(starts: impl IntoIterator<Item = N>, mut neighbours: F) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I
+{
+    let mut order = Vec::new();
+    let mut done = HashSet::new();
+    let mut in_progress = HashSet::new();
+
+    for start in starts {
+        if done.contains(&start) {
+            continue;
+        }
+
+        // Each node is pushed once to expand it and again, with `true`, to finish it once
+        // everything after it has been finished.
+        let mut to_do = vec![(start, false)];
+        while let Some((node, expanded)) = to_do.pop() {
+            if expanded {
+                in_progress.remove(&node);
+                done.insert(node.clone());
+                order.push(node);
+                continue;
+            }
+            if done.contains(&node) {
+                continue;
+            }
+            if !in_progress.insert(node.clone()) {
+                return None;
+            }
+
+            to_do.push((node.clone(), true));
+            for next in neighbours(&node) {
+                if in_progress.contains(&next) {
+                    return None;
+                }
+                if !done.contains(&next) {
+                    to_do.push((next, false));
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Some(order)
+}
+
+/// The most expensive path from `start` to a node satisfying `is_goal` in a graph without
+/// cycles, found by relaxing edges in topological order. `None` if no goal can be reached or the
+/// graph turns out to have a cycle.
+pub fn dag_longest_path<N, C, I, F, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool
+{
+    let mut edges: HashMap<N, Vec<(N, C)>> = HashMap::new();
+    let order = topological_order([start.clone()], |node| {
+        let next: Vec<(N, C)> = neighbours(node).into_iter().collect();
+        edges.insert(node.clone(), next.clone());
+        next.into_iter().map(|(n, _)| n).collect::<Vec<N>>()
+    })?;
+
+    let mut costs = HashMap::from([(start, C::default())]);
+    let mut previous = HashMap::new();
+    let mut best: Option<(C, N)> = None;
+
+    for node in order {
+        let cost = costs[&node];
+        if is_goal(&node) && best.as_ref().is_none_or(|(c, _)| cost > *c) {
+            best = Some((cost, node.clone()));
+        }
+
+        for (next, step_cost) in &edges[&node] {
+            if costs.get(next).is_none_or(|c| cost + *step_cost > *c) {
+                costs.insert(next.clone(), cost + *step_cost);
+                previous.insert(next.clone(), node.clone());
+            }
+        }
+    }
+
+    best.map(|(cost, end)| (cost, rebuild(&previous, end)))
+}
+
+/// The most expensive path from `start` to a node satisfying `is_goal` that never visits a node
+/// twice. This tries every such path, so it's only practical on small graphs such as one made of
+/// the junctions of a maze. Goals end a path rather than being passed through.
+pub fn longest_simple_path<N, C, I, F, G>(start: N, mut neighbours: F, mut is_goal: G) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool
+{
+    // Nodes are numbered as they're found so that the search itself only touches vectors.
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut goals = vec![is_goal(&start)];
+    let mut nodes = vec![start];
+    let mut edges: Vec<Option<Vec<(usize, C)>>> = vec![None];
+    let mut visited = vec![true];
+
+    let mut path = vec![0];
+    let mut best: Option<(C, Vec<usize>)> = None;
+
+    // Each entry is a node on the current path, its cost so far and which of its edges to try next.
+    let mut to_do = vec![(0, C::default(), 0)];
+    while let Some((node, cost, edge_idx)) = to_do.pop() {
+        if goals[node] {
+            if best.as_ref().is_none_or(|(c, _)| cost > *c) {
+                best = Some((cost, path.clone()));
+            }
+        } else {
+            if edges[node].is_none() {
+                let mut next_edges = Vec::new();
+                for (next, step_cost) in neighbours(&nodes[node]) {
+                    let next_id = *ids.entry(next.clone()).or_insert_with(|| {
+                        goals.push(is_goal(&next));
+                        nodes.push(next);
+                        edges.push(None);
+                        visited.push(false);
+                        nodes.len() - 1
+                    });
+                    next_edges.push((next_id, step_cost));
+                }
+                edges[node] = Some(next_edges);
+            }
+
+            if let Some((next, step_cost)) = edges[node].as_ref().unwrap().get(edge_idx).copied() {
+                to_do.push((node, cost, edge_idx + 1));
+                if !visited[next] {
+                    visited[next] = true;
+                    path.push(next);
+                    to_do.push((next, cost + step_cost, 0));
+                }
+                continue;
+            }
+        }
+
+        visited[node] = false;
+        path.pop();
+    }
+
+    best.map(|(cost, path)| (cost, path.into_iter().map(|id| nodes[id].clone()).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -> b -> c -> d with shortcuts a -> c and b -> d, and e on its own.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 4)],
+            'b' => vec![('c', 1), ('d', 5)],
+            'c' => vec![('d', 1)],
+            _ => Vec::new()
+        }
+    }
+
+    fn next(node: &char) -> Vec<char> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    // The same with c -> b added, so b and c form a cycle.
+    fn cyclic_edges(node: &char) -> Vec<(char, u32)> {
+        let mut next = edges(node);
+        if *node == 'c' {
+            next.push(('b', 1));
+        }
+        next
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let paths = bfs(['a'], next);
+        assert_eq!([paths.cost(&'a'), paths.cost(&'c'), paths.cost(&'d'), paths.cost(&'e')], [Some(0), Some(1), Some(2), None]);
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'd']));
+        assert_eq!(paths.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(paths.path_to(&'e'), None);
+
+        let paths = bfs(['e', 'c'], next);
+        assert_eq!(paths.costs().len(), 3);
+        assert_eq!(paths.path_to(&'d'), Some(vec!['c', 'd']));
+        assert_eq!(paths.path_to(&'e'), Some(vec!['e']));
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        assert_eq!(dijkstra(['a'], edges, |node| *node == 'd'), Some((3, vec!['a', 'b', 'c', 'd'])));
+        assert_eq!(dijkstra(['a', 'c'], edges, |node| *node == 'd'), Some((1, vec!['c', 'd'])));
+        assert_eq!(dijkstra(['a'], edges, |node| *node == 'a'), Some((0, vec!['a'])));
+        assert_eq!(dijkstra(['a'], edges, |node| *node == 'e'), None);
+        assert_eq!(dijkstra(['a'], cyclic_edges, |node| *node == 'd'), Some((3, vec!['a', 'b', 'c', 'd'])));
+    }
+
+    #[test]
+    fn a_star_follows_the_heuristic() {
+        // Stepping along a line costs one, except that 5 can't be stepped onto from below.
+        let neighbours = |n: &i32| -> Vec<(i32, i32)> {
+            [n - 1, n + 1].into_iter().filter(|m| !(*n == 4 && *m == 5)).map(|m| (m, 1)).collect()
+        };
+        assert_eq!(a_star([0], neighbours, |n: &i32| (3 - n).abs(), |n| *n == 3), Some((3, vec![0, 1, 2, 3])));
+        assert_eq!(a_star([8], neighbours, |n: &i32| (n - 5).abs(), |n| *n == 5).map(|(cost, _)| cost), Some(3));
+        assert_eq!(a_star([0], neighbours, |_| 0, |n: &i32| *n == 6 || *n == -2).map(|(cost, _)| cost), Some(2));
+    }
+
+    #[test]
+    fn topological_order_puts_nodes_before_their_successors() {
+        assert_eq!(topological_order(['a'], next), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(topological_order(['c', 'a', 'e'], next).map(|order| order.len()), Some(5));
+        assert_eq!(topological_order(['a'], |node: &char| cyclic_edges(node).into_iter().map(|(next, _)| next)), None);
+        assert_eq!(topological_order(['x'], |_| ['x']), None);
+    }
+
+    #[test]
+    fn dag_longest_path_takes_the_dearest_route() {
+        assert_eq!(dag_longest_path('a', edges, |node| *node == 'd'), Some((6, vec!['a', 'b', 'd'])));
+        assert_eq!(dag_longest_path('a', edges, |node| *node == 'a'), Some((0, vec!['a'])));
+        assert_eq!(dag_longest_path('a', edges, |node| *node == 'e'), None);
+        assert_eq!(dag_longest_path('a', cyclic_edges, |node| *node == 'd'), None);
+    }
+
+    #[test]
+    fn longest_simple_path_never_revisits() {
+        assert_eq!(longest_simple_path('a', edges, |node| *node == 'd'), Some((6, vec!['a', 'b', 'd'])));
+        assert_eq!(longest_simple_path('a', cyclic_edges, |node| *node == 'd'), Some((10, vec!['a', 'c', 'b', 'd'])));
+        assert_eq!(longest_simple_path('a', cyclic_edges, |node| *node == 'a'), Some((0, vec!['a'])));
+        assert_eq!(longest_simple_path('a', cyclic_edges, |node| *node == 'e'), None);
+    }
+}
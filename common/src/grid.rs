@@ -0,0 +1,262 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::{Line, ParseError};
+
+/// A `(row, column)` position. Signed, so that stepping off the top or left edge can be expressed
+/// and then rejected (or wrapped) by the grid.
+pub type Point = (i32, i32);
+
+const OFFSETS: [Point; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// A rectangular grid stored row by row, sized from whatever it was built from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {}x{} grid needs {} cells", width, height, width * height);
+
+        Grid { width, height, cells }
+    }
+
+    /// Builds a grid from one line of input per row, calling `parse_cell` with the line, the byte
+    /// index of the character (for `Line::char_error`) and the character itself. Every row must be
+    /// as wide as the first.
+    pub fn parse<F>(input: &str, mut parse_cell: F) -> Result<Self, ParseError>
+    where F: FnMut(Line, usize, char) -> Result<T, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in crate::lines(input) {
+            let row_width = line.text.chars().count();
+            match width {
+                None => { width = Some(row_width); },
+                Some(width) if width != row_width => {
+                    return Err(line.error(line.text, &format!("a row of width {}", width)));
+                },
+                _ => {}
+            }
+
+            for (byte_idx, c) in line.text.char_indices() {
+                cells.push(parse_cell(line, byte_idx, c)?);
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(ParseError::end_of_input(0, "a grid"))
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        let (row, column) = point;
+        row >= 0 && column >= 0 && (row as usize) < self.height && (column as usize) < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[self.offset(point)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            let offset = self.offset(point);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Maps `point` back onto the grid as if it were tiled infinitely in every direction.
+    pub fn wrap(&self, point: Point) -> Point {
+        let (row, column) = point;
+        (row.rem_euclid(self.height as i32), column.rem_euclid(self.width as i32))
+    }
+
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        &self[self.wrap(point)]
+    }
+
+    /// The up/right/down/left neighbours of `point` that lie on the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        wrapping_neighbours(point).filter(|n| self.contains(*n))
+    }
+
+    /// Every point in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    fn offset(&self, point: Point) -> usize {
+        let (row, column) = point;
+        row as usize * self.width + column as usize
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Swaps rows and columns, so row `r` of the result is column `r` of `self`.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Turns the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = self.columns().flat_map(|column| column.cloned().collect::<Vec<_>>().into_iter().rev()).collect();
+
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    /// Turns the grid a quarter turn anticlockwise, so the top row becomes the left column.
+    pub fn rotate_anticlockwise(&self) -> Self {
+        let cells = (0..self.width).rev().flat_map(|column| self.column(column).cloned()).collect();
+
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+/// The up/right/down/left neighbours of `point`, without any bounds check; pair with
+/// `Grid::get_wrapping` to walk an infinitely tiled grid.
+pub fn wrapping_neighbours(point: Point) -> impl Iterator<Item = Point> {
+    let (row, column) = point;
+    OFFSETS.into_iter().map(move |(dr, dc)| (row + dr, column + dc))
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point).unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", point, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two rows of three, so that every view of it is a different shape.
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", |_, _, c| Ok(c)).unwrap()
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().map(|c| c.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = grid();
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!((grid.transpose().width(), grid.transpose().height()), (2, 3));
+    }
+
+    #[test]
+    fn neighbours_stop_at_the_edges() {
+        let grid = grid();
+        assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours((1, 2)).collect::<Vec<_>>(), [(0, 2), (1, 1)]);
+        assert_eq!(grid.neighbours((0, 1)).count(), 3);
+    }
+
+    #[test]
+    fn wrapping_neighbours_tile_the_grid() {
+        let grid = grid();
+        let around: Vec<char> = wrapping_neighbours((0, 0)).map(|point| *grid.get_wrapping(point)).collect();
+        assert_eq!(around, ['d', 'b', 'd', 'c']);
+        assert_eq!(grid.wrap((5, 7)), (1, 1));
+        assert_eq!(grid.wrap((-3, -4)), (1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) is outside the 3x2 grid")]
+    fn indexing_off_the_grid() {
+        let _ = grid()[(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "a 3x2 grid needs 6 cells")]
+    fn too_few_cells() {
+        Grid::new(3, 2, vec!['a'; 5]);
+    }
+}
@@ -0,0 +1,12 @@
+use std::{fs, io::{self, Read}};
+
+/// Reads a puzzle input from `path`, or from stdin when `path` is `-`.
+pub fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer).map_err(|e| format!("failed to read stdin: {}", e))?;
+        return Ok(buffer);
+    }
+
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path, e))
+}
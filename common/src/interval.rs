@@ -0,0 +1,344 @@
+use std::fmt;
+
+/// The integers from `start` up to but not including `end`. An interval whose end isn't after its
+/// start is empty.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: i64,
+    end: i64
+}
+
+impl Interval {
+    /// The half-open interval `start..end`.
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end: end.max(start) }
+    }
+
+    /// The closed interval `first..=last`.
+    pub fn inclusive(first: i64, last: i64) -> Self {
+        Interval::new(first, last + 1)
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    /// The first integer after the interval.
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    /// The last integer in the interval, if there is one.
+    pub fn last(&self) -> Option<i64> {
+        (!self.is_empty()).then_some(self.end - 1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// How many integers the interval holds.
+    pub fn len(&self) -> i64 {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The parts of this interval before and after `other`; either may be empty.
+    pub fn difference(&self, other: &Interval) -> (Interval, Interval) {
+        if other.is_empty() {
+            return (*self, Interval::new(self.end, self.end));
+        }
+
+        (Interval::new(self.start, self.end.min(other.start)), Interval::new(self.start.max(other.end), self.end))
+    }
+
+    /// The values below `at` and the values from `at` up.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end);
+
+        (Interval::new(self.start, at), Interval::new(at, self.end))
+    }
+
+    /// The interval moved along by `offset`, as every value in it would be by `x + offset`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+
+    /// The smallest interval holding `scale * x + offset` for every `x` in this one. A negative
+    /// `scale` flips the interval over, so its ends swap.
+    pub fn map(&self, scale: i64, offset: i64) -> Interval {
+        let Some(last) = self.last() else {
+            return Interval::new(self.start * scale + offset, self.start * scale + offset);
+        };
+
+        let (from, to) = (self.start * scale + offset, last * scale + offset);
+        Interval::inclusive(from.min(to), from.max(to))
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers held as sorted intervals that neither overlap nor touch.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let merged = self.intervals[first..last].iter().fold(interval, |merged, i| {
+            Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+        });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    /// The smallest value in the set.
+    pub fn first(&self) -> Option<i64> {
+        self.intervals.first().map(Interval::start)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intersection = Vec::new();
+        let (mut a, mut b) = (0, 0);
+
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let overlap = self.intervals[a].intersection(&other.intervals[b]);
+            if !overlap.is_empty() {
+                intersection.push(overlap);
+            }
+            if self.intervals[a].end < other.intervals[b].end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+
+        IntervalSet { intervals: intersection }
+    }
+
+    /// The values in this set that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = Vec::new();
+
+        for interval in &self.intervals {
+            let mut rest = *interval;
+            let first = other.intervals.partition_point(|i| i.end <= interval.start);
+            for removed in other.intervals[first..].iter().take_while(|i| i.start < interval.end) {
+                let (before, after) = rest.difference(removed);
+                if !before.is_empty() {
+                    difference.push(before);
+                }
+                rest = after;
+            }
+            if !rest.is_empty() {
+                difference.push(rest);
+            }
+        }
+
+        IntervalSet { intervals: difference }
+    }
+
+    /// The values below `at` and the values from `at` up.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let (below, above) = self.intervals.iter().map(|i| i.split_at(at)).unzip::<_, _, Vec<_>, Vec<_>>();
+
+        (
+            IntervalSet { intervals: below.into_iter().filter(|i| !i.is_empty()).collect() },
+            IntervalSet { intervals: above.into_iter().filter(|i| !i.is_empty()).collect() }
+        )
+    }
+
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet { intervals: self.intervals.iter().map(|i| i.shift(offset)).collect() }
+    }
+
+    /// Each interval mapped by `Interval::map`, so with a `scale` other than 1 or -1 the set holds
+    /// the values between the mapped ones too.
+    pub fn map(&self, scale: i64, offset: i64) -> IntervalSet {
+        self.intervals.iter().map(|i| i.map(scale, offset)).collect()
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl fmt::Debug for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|(start, end)| Interval::new(*start, *end)).collect()
+    }
+
+    #[test]
+    fn inclusive_and_exclusive_bounds() {
+        assert_eq!(Interval::inclusive(3, 5), Interval::new(3, 6));
+        assert_eq!(Interval::inclusive(3, 5).len(), 3);
+        assert_eq!(Interval::inclusive(3, 5).last(), Some(5));
+        assert!(Interval::new(5, 3).is_empty());
+        assert_eq!(Interval::new(5, 3).len(), 0);
+        assert!(!Interval::new(3, 6).contains(6));
+    }
+
+    #[test]
+    fn interval_intersection() {
+        assert_eq!(Interval::new(0, 10).intersection(&Interval::new(5, 15)), Interval::new(5, 10));
+        assert_eq!(Interval::new(0, 10).intersection(&Interval::new(2, 4)), Interval::new(2, 4));
+        assert!(Interval::new(0, 10).intersection(&Interval::new(10, 15)).is_empty());
+    }
+
+    #[test]
+    fn interval_difference() {
+        assert_eq!(Interval::new(0, 10).difference(&Interval::new(3, 5)), (Interval::new(0, 3), Interval::new(5, 10)));
+        assert_eq!(Interval::new(0, 10).difference(&Interval::new(-5, 5)), (Interval::new(0, 0), Interval::new(5, 10)));
+        assert!(Interval::new(0, 10).difference(&Interval::new(-5, 15)).0.is_empty());
+        assert!(Interval::new(0, 10).difference(&Interval::new(-5, 15)).1.is_empty());
+    }
+
+    #[test]
+    fn interval_split_at() {
+        assert_eq!(Interval::new(0, 10).split_at(4), (Interval::new(0, 4), Interval::new(4, 10)));
+        assert_eq!(Interval::new(0, 10).split_at(-4), (Interval::new(0, 0), Interval::new(0, 10)));
+        assert_eq!(Interval::new(0, 10).split_at(40), (Interval::new(0, 10), Interval::new(10, 10)));
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_touching() {
+        assert_eq!(set(&[(0, 5), (10, 15), (5, 7)]).intervals(), &[Interval::new(0, 7), Interval::new(10, 15)]);
+        assert_eq!(set(&[(0, 5), (10, 15), (3, 12)]).intervals(), &[Interval::new(0, 15)]);
+        assert_eq!(set(&[(10, 15), (0, 5), (20, 20)]).intervals(), &[Interval::new(0, 5), Interval::new(10, 15)]);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(a.len(), 20);
+        assert!(a.contains(29) && !a.contains(15));
+    }
+
+    #[test]
+    fn set_split_and_shift() {
+        let (below, above) = set(&[(0, 10), (20, 30)]).split_at(25);
+        assert_eq!(below, set(&[(0, 10), (20, 25)]));
+        assert_eq!(above, set(&[(25, 30)]));
+        assert_eq!(below.shift(-5).first(), Some(-5));
+    }
+
+    #[test]
+    fn affine_map() {
+        assert_eq!(Interval::new(0, 3).map(2, 1), Interval::new(1, 6));
+        assert_eq!(Interval::new(0, 3).map(-1, 0), Interval::new(-2, 1));
+        assert_eq!(Interval::new(2, 5).map(-3, 10), Interval::inclusive(-2, 4));
+        assert_eq!(Interval::new(2, 5).map(0, 7), Interval::inclusive(7, 7));
+        assert_eq!(Interval::new(2, 5).map(1, 7), Interval::new(2, 5).shift(7));
+        assert!(Interval::new(5, 5).map(-2, 1).is_empty());
+
+        let mapped = set(&[(0, 2), (5, 7)]).map(-1, 10);
+        assert_eq!(mapped.intervals(), &[Interval::new(4, 6), Interval::new(9, 11)]);
+        assert_eq!(set(&[(0, 2), (3, 5)]).map(-2, 0), set(&[(-8, -5), (-2, 1)]));
+    }
+
+    // Checks the set operations against plain sets of small integers.
+    #[test]
+    fn matches_brute_force() {
+        use std::collections::BTreeSet;
+        let mut rng = crate::Rng::new(13);
+
+        let random_set = |rng: &mut crate::Rng| -> IntervalSet {
+            (0..rng.range(0..5)).map(|_| {
+                let start = rng.range(0..30);
+                Interval::new(start, start + rng.range(0..8))
+            }).collect()
+        };
+        let values = |set: &IntervalSet| -> BTreeSet<i64> { (-10..50).filter(|v| set.contains(*v)).collect() };
+
+        for _ in 0..500 {
+            let (a, b) = (random_set(&mut rng), random_set(&mut rng));
+            let (va, vb) = (values(&a), values(&b));
+
+            assert_eq!(values(&a.union(&b)), &va | &vb);
+            assert_eq!(values(&a.intersection(&b)), &va & &vb);
+            assert_eq!(values(&a.difference(&b)), &va - &vb);
+            for result in [a.union(&b), a.intersection(&b), a.difference(&b)] {
+                assert_eq!(result, result.intervals().iter().copied().collect());
+            }
+            assert_eq!(a.len(), va.len() as i64);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+pub mod arith;
+mod check;
+mod cycle;
+pub mod graph;
+mod grid;
+mod input;
+mod interval;
+mod parse;
+pub mod render;
+mod rng;
+mod run;
+pub use arith::{Int, SolveError, UInt};
+pub use check::differential;
+pub use cycle::{Cycle, Period};
+pub use grid::{wrapping_neighbours, Grid, Point};
+pub use input::read_input;
+pub use interval::{Interval, IntervalSet};
+pub use parse::{lines, Line, Lines, ParseError};
+pub use rng::Rng;
+pub use run::{exit, run_day, solve_day};
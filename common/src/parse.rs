@@ -0,0 +1,111 @@
+use std::{error::Error, fmt, iter::Enumerate, str::{self, FromStr}};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, expected: &str) -> Self {
+        ParseError { line, column, token: token.to_owned(), expected: expected.to_owned() }
+    }
+
+    /// For input that stops before `expected` is found; `line_idx` is the zero-based index of the
+    /// missing line.
+    pub fn end_of_input(line_idx: usize, expected: &str) -> Self {
+        ParseError::new(line_idx + 1, 1, "", expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+
+        if self.token.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found '{}'", self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input along with its zero-based index, so that errors raised while parsing
+/// slices of it can report where they happened.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub idx: usize,
+    pub text: &'a str
+}
+
+pub struct Lines<'a> {
+    lines: Enumerate<str::Lines<'a>>,
+    next_idx: usize
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines { lines: input.lines().enumerate(), next_idx: 0 }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, text) = self.lines.next()?;
+        self.next_idx = idx + 1;
+
+        Some(Line { idx, text })
+    }
+}
+
+impl<'a> Lines<'a> {
+    /// Like `next`, but running out of input is reported as an error.
+    pub fn expect(&mut self, expected: &str) -> Result<Line<'a>, ParseError> {
+        let next_idx = self.next_idx;
+
+        self.next().ok_or_else(|| ParseError::end_of_input(next_idx, expected))
+    }
+}
+
+impl<'a> Line<'a> {
+    /// `token` should be a slice of `self.text`; anything else is reported at column 1.
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let column = match self.text.get(..offset) {
+            Some(before) if offset + token.len() <= self.text.len() => before.chars().count() + 1,
+            _ => 1
+        };
+
+        ParseError::new(self.idx + 1, column, token, expected)
+    }
+
+    /// Reports the character starting at byte `byte_idx`, as yielded by `char_indices`.
+    pub fn char_error(&self, byte_idx: usize, expected: &str) -> ParseError {
+        let length = self.text[byte_idx..].chars().next().map_or(0, char::len_utf8);
+
+        self.error(&self.text[byte_idx..byte_idx + length], expected)
+    }
+
+    /// Checks that the whole line is exactly `text`.
+    pub fn expect(&self, text: &str) -> Result<(), ParseError> {
+        if self.text == text {
+            Ok(())
+        } else if text.is_empty() {
+            Err(self.error(self.text, "an empty line"))
+        } else {
+            Err(self.error(self.text, &format!("'{}'", text)))
+        }
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, "a number"))
+    }
+
+    pub fn split_once(&self, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter).ok_or_else(|| self.error(text, &format!("'{}'", delimiter)))
+    }
+}
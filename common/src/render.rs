@@ -0,0 +1,135 @@
+use crate::Grid;
+
+/// An RGB colour for one cell of a rendered grid.
+pub type Colour = [u8; 3];
+
+pub const BLACK: Colour = [0, 0, 0];
+pub const WHITE: Colour = [255, 255, 255];
+pub const GREY: Colour = [96, 96, 96];
+pub const YELLOW: Colour = [250, 210, 40];
+pub const RED: Colour = [220, 40, 40];
+pub const BLUE: Colour = [40, 90, 220];
+pub const GREEN: Colour = [50, 180, 80];
+
+/// Blends from `from` at 0.0 to `to` at 1.0; `fraction` is clamped to that range.
+pub fn blend(from: Colour, to: Colour, fraction: f64) -> Colour {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let mut colour = [0; 3];
+    for idx in 0..3 {
+        colour[idx] = (f64::from(from[idx]) + (f64::from(to[idx]) - f64::from(from[idx])) * fraction).round() as u8;
+    }
+
+    colour
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Ppm,
+    Svg
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "ppm" => Some(Self::Ppm),
+            "svg" => Some(Self::Svg),
+            _ => None
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            Self::Svg => "svg"
+        }
+    }
+
+    /// Encodes `image` with each cell drawn as a `scale` by `scale` square.
+    pub fn encode(&self, image: &Grid<Colour>, scale: usize) -> Vec<u8> {
+        match self {
+            Self::Ppm => to_ppm(image, scale),
+            Self::Svg => to_svg(image, scale).into_bytes()
+        }
+    }
+}
+
+/// A binary (P6) PPM.
+pub fn to_ppm(image: &Grid<Colour>, scale: usize) -> Vec<u8> {
+    let mut bytes = format!("P6\n{} {}\n255\n", image.width() * scale, image.height() * scale).into_bytes();
+
+    for row in image.rows() {
+        let mut line = Vec::with_capacity(row.len() * scale * 3);
+        for colour in row {
+            for _ in 0..scale {
+                line.extend_from_slice(colour);
+            }
+        }
+        for _ in 0..scale {
+            bytes.extend_from_slice(&line);
+        }
+    }
+
+    bytes
+}
+
+/// An SVG with one rectangle per run of same-coloured cells in a row, which keeps mostly empty
+/// grids small.
+pub fn to_svg(image: &Grid<Colour>, scale: usize) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {2} {3}\" shape-rendering=\"crispEdges\">\n",
+        image.width() * scale, image.height() * scale, image.width(), image.height()
+    );
+
+    for (row_idx, row) in image.rows().enumerate() {
+        let mut start = 0;
+        while start < row.len() {
+            let colour = row[start];
+            let end = start + row[start..].iter().take_while(|c| **c == colour).count();
+            svg += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                start, row_idx, end - start, colour[0], colour[1], colour[2]
+            );
+            start = end;
+        }
+    }
+
+    svg + "</svg>\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Grid<Colour> {
+        Grid::new(3, 2, vec![RED, RED, BLUE, WHITE, WHITE, WHITE])
+    }
+
+    #[test]
+    fn ppm() {
+        let bytes = to_ppm(&image(), 2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&bytes[..header.len()], header);
+
+        let pixels: Vec<&[u8]> = bytes[header.len()..].chunks(3).collect();
+        assert_eq!(pixels.len(), 6 * 4);
+        assert_eq!(pixels[..6], [&RED[..], &RED, &RED, &RED, &BLUE, &BLUE]);
+        assert_eq!(pixels[6..12], pixels[..6]);
+        assert!(pixels[12..].iter().all(|pixel| *pixel == WHITE));
+    }
+
+    #[test]
+    fn svg() {
+        assert_eq!(Format::Svg.encode(&image(), 4), b"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"8\" viewBox=\"0 0 3 2\" shape-rendering=\"crispEdges\">\n\
+            <rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#dc2828\"/>\n\
+            <rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#285adc\"/>\n\
+            <rect x=\"0\" y=\"1\" width=\"3\" height=\"1\" fill=\"#ffffff\"/>\n\
+            </svg>\n");
+    }
+
+    #[test]
+    fn blends() {
+        assert_eq!(blend(BLACK, WHITE, 0.5), [128, 128, 128]);
+        assert_eq!(blend(RED, BLUE, -1.0), RED);
+        assert_eq!(blend(RED, BLUE, 2.0), BLUE);
+    }
+}
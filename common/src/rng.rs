@@ -0,0 +1,62 @@
+use std::ops::Range;
+
+/// A small seeded generator (SplitMix64) for producing random puzzle inputs; not suitable for
+/// anything that needs real randomness.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range {:?}", range);
+        let width = (range.end - range.start) as u64;
+
+        range.start + (self.next_u64() % width) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..len as i64) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let (mut first, mut second) = (Rng::new(7), Rng::new(7));
+        assert_eq!((0..10).map(|_| first.next_u64()).collect::<Vec<_>>(), (0..10).map(|_| second.next_u64()).collect::<Vec<_>>());
+        assert!((0..1000).map(|_| first.range(-3..4)).all(|value| (-3..4).contains(&value)));
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range 5..5")]
+    fn empty_range() {
+        Rng::new(0).range(5..5);
+    }
+}
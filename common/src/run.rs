@@ -0,0 +1,50 @@
+use std::{env, fmt::Display, process::ExitCode};
+
+use crate::{read_input, ParseError, SolveError};
+
+/// Prints the answer to each part in turn, stopping at the first that fails.
+pub fn solve_day<A, B, E1, E2, F1, F2>(input: &str, part1: F1, part2: F2) -> Result<(), SolveError>
+where
+    A: Display,
+    B: Display,
+    E1: Into<SolveError>,
+    E2: Into<SolveError>,
+    F1: FnOnce(&str) -> Result<A, E1>,
+    F2: FnOnce(&str) -> Result<B, E2>
+{
+    println!("{}", part1(input).map_err(Into::into)?);
+    println!("{}", part2(input).map_err(Into::into)?);
+
+    Ok(())
+}
+
+/// Prints the error if there is one, and gives the exit status for the result.
+pub fn exit<E: Display>(result: Result<(), E>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The whole of a day's `main`: reads the input at the path given as the only argument, `-` for
+/// stdin, or `./data.txt`, and prints the answer to each part once it's checked that it parses.
+pub fn run_day<A, B, E1, E2, F1, F2>(parse: fn(&str) -> Result<(), ParseError>, part1: F1, part2: F2) -> ExitCode
+where
+    A: Display,
+    B: Display,
+    E1: Into<SolveError>,
+    E2: Into<SolveError>,
+    F1: FnOnce(&str) -> Result<A, E1>,
+    F2: FnOnce(&str) -> Result<B, E2>
+{
+    let path = env::args().nth(1).unwrap_or_else(|| "./data.txt".to_owned());
+    let input = match read_input(&path) {
+        Ok(input) => input,
+        Err(message) => return exit(Err(message))
+    };
+
+    exit(parse(&input).map_err(SolveError::from).and_then(|()| solve_day(&input, part1, part2)))
+}
@@ -0,0 +1,384 @@
+use std::{cmp::Reverse, collections::{HashMap, VecDeque}};
+use common::{arith, step, Line, ParseError, SolveError, UInt};
+
+const ENGLISH: &str = include_str!("../vocabulary/english.txt");
+
+/// Which spellings of a digit count when reading a calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only the digits `1` to `9`.
+    Digits,
+    /// The digits and the words `one` to `nine`.
+    Words
+}
+
+impl Mode {
+    pub fn vocabulary(self) -> Vocabulary {
+        match self {
+            Mode::Digits => Vocabulary::parse("").unwrap(),
+            Mode::Words => Vocabulary::parse(ENGLISH).expect("the English vocabulary doesn't parse")
+        }
+    }
+}
+
+/// The tokens that stand for numbers in a calibration line: the digits `1` to `9` and any words
+/// added to them.
+#[derive(Debug)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u32)>,
+    matcher: Matcher
+}
+
+impl Vocabulary {
+    /// Reads a vocabulary file, with a `word = number` entry on each line. Blank lines and lines
+    /// starting with `#` are ignored, and the digits are always included without being listed.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut tokens: Vec<(String, u32)> = (1..=9).map(|digit| (digit.to_string(), digit)).collect();
+
+        for line in common::lines(text) {
+            let entry = line.text.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+
+            let (word, number) = line.split_once(entry, "=")?;
+            let word = word.trim();
+            if word.is_empty() || word.contains(char::is_whitespace) {
+                return Err(line.error(word, "a single word"));
+            }
+            if tokens.iter().any(|(token, _)| token == word) {
+                return Err(line.error(word, "a word not already in the vocabulary"));
+            }
+            tokens.push((word.to_owned(), line.number(number.trim())?));
+        }
+
+        let matcher = Matcher::new(tokens.iter().map(|(token, _)| token.as_str()));
+        Ok(Vocabulary { tokens, matcher })
+    }
+}
+
+/// What to do with a line that has no digit in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoDigit {
+    /// Leave the line out.
+    Skip,
+    /// Count the line as a value of zero.
+    Zero,
+    /// Fail with a parse error pointing at the line.
+    Error
+}
+
+impl NoDigit {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "skip" => Some(Self::Skip),
+            "zero" => Some(Self::Zero),
+            "error" => Some(Self::Error),
+            _ => None
+        }
+    }
+}
+
+/// Where one of the patterns was found in a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Match {
+    start: usize,
+    end: usize,
+    pattern: usize
+}
+
+/// An Aho-Corasick automaton over the patterns, which finds every match in a line, overlapping
+/// ones included, in a single pass.
+#[derive(Debug)]
+struct Matcher {
+    lengths: Vec<usize>,
+    // The state to move to from each state on each byte, with the failure links already followed.
+    next: Vec<[usize; 256]>,
+    // The patterns that end at each state, including the ones ending at its failure states.
+    outputs: Vec<Vec<usize>>
+}
+
+impl Matcher {
+    fn new<'a>(patterns: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut lengths = Vec::new();
+        let mut next = vec![[0; 256]];
+        let mut outputs = vec![Vec::new()];
+        let mut children: Vec<HashMap<u8, usize>> = vec![HashMap::new()];
+
+        for (pattern, word) in patterns.into_iter().enumerate() {
+            assert!(!word.is_empty(), "an empty pattern matches everywhere");
+            let mut state = 0;
+            for byte in word.bytes() {
+                state = match children[state].get(&byte) {
+                    Some(child) => *child,
+                    None => {
+                        next.push([0; 256]);
+                        outputs.push(Vec::new());
+                        children.push(HashMap::new());
+                        children[state].insert(byte, next.len() - 1);
+                        next.len() - 1
+                    }
+                };
+            }
+            outputs[state].push(pattern);
+            lengths.push(word.len());
+        }
+
+        // Breadth first, so that a state's failure state (which is shallower) is finished before it.
+        let mut fail = vec![0; next.len()];
+        let mut to_do: VecDeque<usize> = VecDeque::from([0]);
+        while let Some(state) = to_do.pop_front() {
+            let inherited = if state == 0 { Vec::new() } else { outputs[fail[state]].clone() };
+            outputs[state].extend(inherited);
+
+            let fallback = next[fail[state]];
+            for byte in 0..256 {
+                next[state][byte] = match children[state].get(&(byte as u8)) {
+                    Some(child) => {
+                        fail[*child] = if state == 0 { 0 } else { fallback[byte] };
+                        to_do.push_back(*child);
+                        *child
+                    }
+                    None => if state == 0 { 0 } else { fallback[byte] }
+                };
+            }
+        }
+
+        Matcher { lengths, next, outputs }
+    }
+
+    /// The first and last matches in `text` by where they start, preferring the longer of two that
+    /// start together.
+    fn first_and_last(&self, text: &str) -> Option<(Match, Match)> {
+        let mut found: Option<(Match, Match)> = None;
+        let mut state = 0;
+
+        for (idx, byte) in text.bytes().enumerate() {
+            state = self.next[state][byte as usize];
+            for pattern in &self.outputs[state] {
+                let end = idx + 1;
+                let here = Match { start: end - self.lengths[*pattern], end, pattern: *pattern };
+                found = Some(match found {
+                    None => (here, here),
+                    Some((first, last)) => (
+                        if (here.start, Reverse(here.end)) < (first.start, Reverse(first.end)) { here } else { first },
+                        if (here.start, here.end) > (last.start, last.end) { here } else { last }
+                    )
+                });
+            }
+        }
+
+        found
+    }
+}
+
+/// The first and last numbers found in `text` and the calibration value they make.
+fn calibration(text: &str, vocabulary: &Vocabulary) -> Option<(Match, Match, u32)> {
+    let (first, last) = vocabulary.matcher.first_and_last(text)?;
+    let first_digit = to_digit(vocabulary.tokens[first.pattern].1, true);
+    let last_digit = to_digit(vocabulary.tokens[last.pattern].1, false);
+
+    Some((first, last, first_digit * 10 + last_digit))
+}
+
+fn get_value_from_string(line: Line, vocabulary: &Vocabulary, no_digit: NoDigit) -> Result<Option<u32>, ParseError> {
+    let Some((_, _, value)) = calibration(line.text, vocabulary) else {
+        return match no_digit {
+            NoDigit::Skip => Ok(None),
+            NoDigit::Zero => Ok(Some(0)),
+            NoDigit::Error => Err(line.error(line.text, "a digit"))
+        };
+    };
+
+    Ok(Some(value))
+}
+
+// A number with more than one digit reads as if it had been written out in digits, so it gives its
+// leading digit when it comes first on a line and its trailing digit when it comes last.
+fn to_digit(number: u32, leading: bool) -> u32 {
+    if leading {
+        number / 10_u32.pow(number.checked_ilog10().unwrap_or(0))
+    } else {
+        number % 10
+    }
+}
+
+/// The sum of the calibration values of every line, reading numbers from `vocabulary` and dealing
+/// with lines that have none as `no_digit` says.
+pub fn sum_values(input: &str, vocabulary: &Vocabulary, no_digit: NoDigit) -> Result<UInt, SolveError> {
+    let mut sum = 0;
+    for line in common::lines(input) {
+        if let Some(value) = get_value_from_string(line, vocabulary, no_digit)? {
+            sum = arith::add(sum, UInt::from(value), step!("calibration total"))?;
+        }
+    }
+
+    Ok(sum)
+}
+
+fn describe(read: Option<(Match, Match, u32)>) -> String {
+    read.map_or("no digit".to_owned(), |(_, _, value)| value.to_string())
+}
+
+/// Shows how each line is read: its calibration value, with the first and last numbers found in it
+/// underlined along with the token each one was and the digit it gives. With `only_differing` just
+/// the lines whose value changes when reading digits alone are shown, along with that value.
+pub fn explain(input: &str, vocabulary: &Vocabulary, only_differing: bool) -> String {
+    let digits = Mode::Digits.vocabulary();
+    let mut explanation = String::new();
+
+    for line in common::lines(input) {
+        let read = calibration(line.text, vocabulary);
+        let digits_only = calibration(line.text, &digits);
+        let differs = digits_only.map(|(_, _, value)| value) != read.map(|(_, _, value)| value);
+        if only_differing && !differs {
+            continue;
+        }
+
+        let margin = format!("line {}: ", line.idx + 1);
+        explanation += &format!("{}{} = {}", margin, line.text, describe(read));
+        if only_differing {
+            explanation += &format!(" (digits only: {})", describe(digits_only));
+        }
+        explanation += "\n";
+        let Some((first, last, _)) = read else {
+            continue;
+        };
+        for (label, found, leading) in [("first", first, true), ("last", last, false)] {
+            let (token, number) = &vocabulary.tokens[found.pattern];
+            let column = margin.len() + line.text[..found.start].chars().count();
+            explanation += &format!(
+                "{}{} {} '{}' gives {}\n",
+                " ".repeat(column), "^".repeat(token.chars().count()), label, token, to_digit(*number, leading)
+            );
+        }
+    }
+
+    explanation
+}
+
+// Lines are parsed in the same pass that solves them, so there is nothing to do up front.
+pub fn parse(_input: &str) -> Result<(), ParseError> {
+    Ok(())
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    sum_values(input, &Mode::Digits.vocabulary(), NoDigit::Error) // 55971
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    sum_values(input, &Mode::Words.vocabulary(), NoDigit::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE_1).unwrap(), 142);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE_2).unwrap(), 281);
+    }
+
+    fn value(text: &str, vocabulary: &Vocabulary) -> Option<u32> {
+        get_value_from_string(Line { idx: 0, text }, vocabulary, NoDigit::Skip).unwrap()
+    }
+
+    #[test]
+    fn digits_mode_ignores_words() {
+        assert_eq!(value("xtwone3four", &Mode::Digits.vocabulary()), Some(33));
+        assert_eq!(value("xtwone3four", &Mode::Words.vocabulary()), Some(24));
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let english = Mode::Words.vocabulary();
+        assert_eq!(value("twone", &english), Some(21));
+        assert_eq!(value("eightwo", &english), Some(82));
+        assert_eq!(value("oneight", &english), Some(18));
+        assert_eq!(value("sevenine", &english), Some(79));
+    }
+
+    #[test]
+    fn other_languages() {
+        let french = Vocabulary::parse(include_str!("../vocabulary/french.txt")).unwrap();
+        assert_eq!(value("xhuitrois", &french), Some(83));
+        assert_eq!(value("cinquatre", &french), Some(54));
+        assert_eq!(value("one7deux", &french), Some(72));
+
+        let german = Vocabulary::parse(include_str!("../vocabulary/german.txt")).unwrap();
+        assert_eq!(value("zweins", &german), Some(21));
+        assert_eq!(value("einsechs", &german), Some(16));
+        assert_eq!(value("fünfzig", &german), Some(55));
+    }
+
+    #[test]
+    fn zero_and_multi_digit_words() {
+        let vocabulary = Vocabulary::parse("zero = 0\nten = 10\nseven = 7\nseventeen = 17\n").unwrap();
+        assert_eq!(value("zero", &vocabulary), Some(0));
+        assert_eq!(value("ten3zero", &vocabulary), Some(10));
+        assert_eq!(value("seventeen", &vocabulary), Some(17));
+        assert_eq!(value("4seventeen", &vocabulary), Some(47));
+        assert_eq!(value("seventen", &vocabulary), Some(70));
+    }
+
+    #[test]
+    fn rejects_bad_vocabularies() {
+        let error = Vocabulary::parse("# comment\n\none = 1\none = 2").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        let error = Vocabulary::parse("twenty one = 21").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = Vocabulary::parse("one = un").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+
+    #[test]
+    fn explains_each_line() {
+        let english = Mode::Words.vocabulary();
+        assert_eq!(explain("xtwone3four\nnothing", &english, false), "\
+line 1: xtwone3four = 24
+         ^^^ first 'two' gives 2
+               ^^^^ last 'four' gives 4
+line 2: nothing = no digit
+");
+        assert_eq!(explain("1abc2\ntwone\n", &english, true), "\
+line 2: twone = 21 (digits only: no digit)
+        ^^^ first 'two' gives 2
+          ^^^ last 'one' gives 1
+");
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let digits = Mode::Digits.vocabulary();
+        let input = "1abc2\nnothing here\ntreb7uchet\n";
+        assert_eq!(sum_values(input, &digits, NoDigit::Skip).unwrap(), 89);
+        assert_eq!(sum_values(input, &digits, NoDigit::Zero).unwrap(), 89);
+
+        let Err(SolveError::Parse(error)) = sum_values(input, &digits, NoDigit::Error) else {
+            panic!("a line without a digit was let through");
+        };
+        assert_eq!(error.line, 2);
+    }
+}
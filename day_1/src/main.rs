@@ -1,74 +1,79 @@
-use std::fs::read_to_string;
-use std::collections::HashMap;
+use std::{env, process::ExitCode};
 
-static NUMBERS: [&str; 18] = [
-    "1", "2", "3", "4", "5", "6", "7", "8", "9",
-    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"
-];
+use day_1::{Mode, NoDigit, Vocabulary};
 
-fn parse_input() -> Vec<String> {
-    let mut result = Vec::new();
+const USAGE: &str = "usage: day_1 [<path>|-] [--no-digit skip|zero|error] [--vocabulary <path>]
+             [--explain [--only-differing]]";
 
-    for line in read_to_string("./data.txt").unwrap().lines() {
-        result.push(line.to_string())
-    }
-
-    result
+struct Args {
+    path: String,
+    no_digit: NoDigit,
+    vocabulary: Option<String>,
+    explain: bool,
+    only_differing: bool
 }
 
-fn get_value_from_string(word: &str) -> u32 {
-    let mut matches: Vec<(usize, &str)> = Vec::new();
-
-    for possible_num in NUMBERS {
-        let mut mi = word.match_indices(possible_num);
-
-        if let Some(x) =  mi.next() {
-            matches.push(x);
-
-            if let Some(y) =  mi.last() {
-                matches.push(y);
-            }
+fn parse_args() -> Result<Args, String> {
+    let mut path = None;
+    let mut no_digit = NoDigit::Error;
+    let mut vocabulary = None;
+    let mut explain = false;
+    let mut only_differing = false;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--no-digit" => {
+                let policy = args.next().ok_or(USAGE)?;
+                no_digit = NoDigit::parse(&policy).ok_or_else(|| format!("invalid policy '{}'", policy))?;
+            },
+            "--vocabulary" => { vocabulary = Some(args.next().ok_or(USAGE)?); },
+            "--explain" => { explain = true; },
+            "--only-differing" => { only_differing = true; },
+            _ if path.is_none() => { path = Some(arg); },
+            _ => return Err(USAGE.to_owned())
         }
-        
     }
 
-    matches.sort_by_key(|k| k.0);
-
-    let first = matches[0].1;
-    let last = matches.last().unwrap_or(&matches[0]).1;
-
-    let first_digit = to_digit(first);
-    let last_digit = to_digit(last);
+    if only_differing && !explain {
+        return Err("--only-differing needs --explain".to_owned());
+    }
 
-    first_digit * 10 + last_digit
+    Ok(Args { path: path.unwrap_or_else(|| "./data.txt".to_owned()), no_digit, vocabulary, explain, only_differing })
 }
 
-fn to_digit(word: &str) -> u32 {
-    let map: HashMap<&str, u32> = HashMap::from([
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9)
-    ]);
-    
-    word.chars().collect::<Vec<_>>()[0].to_digit(10).unwrap_or_else(
-        || *map.get(word).unwrap()
-    )
+fn load_vocabulary(path: Option<&str>) -> Result<Vocabulary, String> {
+    let Some(path) = path else {
+        return Ok(Mode::Words.vocabulary());
+    };
+
+    let text = common::read_input(path)?;
+    Vocabulary::parse(&text).map_err(|e| format!("{}: {}", path, e))
 }
 
-fn main() {
-    let words: Vec<String> = parse_input();
-    let mut sum = 0;
-    for word in &words {
-        sum += get_value_from_string(word)
+/// Solves the input at the given path, `-` for stdin, or `./data.txt`. Lines without a digit are
+/// an error unless `--no-digit` says to skip them or count them as zero, and the second answer
+/// reads words from the `--vocabulary` file if one is given rather than in English. `--explain`
+/// shows how each line is read instead, or with `--only-differing` just the lines that read
+/// differently with digits alone.
+fn main() -> ExitCode {
+    let loaded = parse_args().and_then(|args| {
+        let vocabulary = load_vocabulary(args.vocabulary.as_deref())?;
+        Ok((common::read_input(&args.path)?, vocabulary, args))
+    });
+    let (input, vocabulary, args) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => return common::exit(Err(message))
+    };
+
+    if args.explain {
+        print!("{}", day_1::explain(&input, &vocabulary, args.only_differing));
+        return ExitCode::SUCCESS;
     }
 
-    println!("{}", sum)
+    common::exit(common::solve_day(
+        &input,
+        |input| day_1::sum_values(input, &Mode::Digits.vocabulary(), args.no_digit),
+        |input| day_1::sum_values(input, &vocabulary, args.no_digit)
+    ))
 }
-
-// 55971
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use common::{graph, render::{self, Colour}, Grid, ParseError, Point};
+
+type Pipes = Grid<Option<Pipe>>;
+
+fn parse_input(input: &str) -> Result<(Point, Pipes), ParseError> {
+    let mut unjoined_start = None;
+    let tiles = Grid::parse(input, |line, idx, loc| {
+        if loc == 'S' && unjoined_start.is_none() {
+            unjoined_start = Some(line.char_error(idx, "a start between two pipes that lead into it"));
+        }
+        match Pipe::parse(&loc) {
+            Some(_) => Ok(loc),
+            None if loc == '.' => Ok(loc),
+            None => Err(line.char_error(idx, "a pipe or '.'"))
+        }
+    })?;
+
+    let start = tiles.iter().find(|(_, loc)| **loc == 'S').map(|(start, _)| start);
+
+    match (start, unjoined_start) {
+        (Some(start), Some(unjoined_start)) => {
+            let mut pipes = tiles.map(Pipe::parse);
+            pipes[start] = Some(Pipe::infer(&start, &pipes).ok_or(unjoined_start)?);
+            Ok((start, pipes))
+        },
+        _ => Err(ParseError::end_of_input(input.lines().count(), "a start 'S'"))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Pipe {
+    fn parse(symbol: &char) -> Option<Self> {
+        match symbol {
+            '|' => Some(Self::Vertical),
+            '-' => Some(Self::Horizontal),
+            'L' => Some(Self::NorthEast),
+            'J' => Some(Self::NorthWest),
+            '7' => Some(Self::SouthWest),
+            'F' => Some(Self::SouthEast),
+            'S' => Some(Self::SouthWest),
+            _ => None
+        }
+    }
+
+    // The start is whichever pipe joins up with both of the neighbours that lead back into it.
+    fn infer(start: &Point, pipes: &Pipes) -> Option<Self> {
+        let leads_to_start = |point: &Point| match pipes.get(*point) {
+            Some(Some(pipe)) => {
+                let (connects_1, connects_2) = pipe.connect_to(point);
+                connects_1 == *start || connects_2 == *start
+            },
+            _ => false
+        };
+
+        [Self::Vertical, Self::Horizontal, Self::NorthEast, Self::NorthWest, Self::SouthWest, Self::SouthEast]
+            .into_iter()
+            .find(|pipe| {
+                let (connects_1, connects_2) = pipe.connect_to(start);
+                leads_to_start(&connects_1) && leads_to_start(&connects_2)
+            })
+    }
+
+    fn connect_to(&self, start: &Point) -> (Point, Point) {
+        let (row, column) = *start;
+        match self {
+            Self::Vertical   => ((row + 1, column), (row - 1, column)),
+            Self::Horizontal => ((row, column + 1), (row, column - 1)),
+            Self::NorthEast => ((row - 1, column), (row, column + 1)),
+            Self::NorthWest => ((row - 1, column), (row, column - 1)),
+            Self::SouthWest => ((row + 1, column), (row, column - 1)),
+            Self::SouthEast => ((row + 1, column), (row, column + 1)),
+        }
+    }
+}
+
+fn is_pipe(point: &Point, pipes: &Pipes) -> bool {
+    matches!(pipes.get(*point), Some(Some(_)))
+}
+
+fn walk(start: Point, pipes: &Pipes) -> HashMap<Point, usize> {
+    graph::bfs([start], |location| {
+        let Some(pipe) = &pipes[*location] else { unreachable!("the walk only steps onto pipes"); };
+
+        let (connects_1, connects_2) = pipe.connect_to(location);
+        [connects_1, connects_2].into_iter().filter(|c| is_pipe(c, pipes))
+    }).into_costs()
+}
+
+fn get_loop<'a>(pipes: &'a Pipes, distances: &HashMap<Point, usize>) -> Grid<Option<&'a Pipe>> {
+    let mut loop_pipes = Grid::filled(pipes.width(), pipes.height(), None);
+    for p in distances.keys() {
+        loop_pipes[*p] = pipes[*p].as_ref();
+    }
+
+    loop_pipes
+}
+
+fn find_enclosing_space(pipes: Grid<Option<&Pipe>>) -> Vec<Point> {
+    let mut spaces = Vec::new();
+
+    for (row_idx, row) in pipes.rows().enumerate() {
+        let mut in_loop = false;
+        let mut wall_start = None;
+
+        for (column_idx, space) in row.iter().enumerate() {
+
+            if let Some(pipe) = space {
+                match pipe {
+                    Pipe::Vertical => { in_loop = !in_loop; },
+                    Pipe::Horizontal => { assert!(wall_start.is_some()); },
+                    Pipe::NorthEast => {
+                        assert!(wall_start.is_none());
+                        wall_start = Some(pipe); 
+                    },
+                    Pipe::SouthEast => {
+                        assert!(wall_start.is_none());
+                        wall_start = Some(pipe); 
+                    },
+                    Pipe::NorthWest => {
+                        match wall_start {
+                            Some(Pipe::SouthEast) => {
+                                wall_start = None;
+                                in_loop = !in_loop;
+                            },
+                            Some(Pipe::NorthEast) => { wall_start = None; },
+                            _ => panic!("Oh No!")
+                        }
+                    },
+                    Pipe::SouthWest => {
+                        match wall_start {
+                            Some(Pipe::NorthEast) => {
+                                wall_start = None;
+                                in_loop = !in_loop;
+                            },
+                            Some(Pipe::SouthEast) => { wall_start = None; },
+                            _ => panic!("Oh No!")
+                        }
+                    },
+                }
+
+            } else {
+                assert!(wall_start.is_none());
+                if in_loop {
+                    spaces.push((row_idx as i32, column_idx as i32));
+                }
+            }
+        }
+    }
+
+    spaces
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let (start, pipes) = parse_input(input)?;
+
+    let distances =  walk(start, &pipes);
+    Ok(*distances.values().max().unwrap())
+}
+
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let (start, pipes) = parse_input(input)?;
+
+    let distances =  walk(start, &pipes);
+    Ok(find_enclosing_space(get_loop(&pipes, &distances)).len())
+}
+
+/// Frames of the walk spreading out from the start along the loop, coloured by distance, with
+/// the enclosed tiles picked out in the last frame.
+pub fn render(input: &str) -> Result<Vec<Grid<Colour>>, ParseError> {
+    let (start, pipes) = parse_input(input)?;
+    let distances = walk(start, &pipes);
+    let furthest = *distances.values().max().unwrap();
+
+    let mut frame = pipes.map(|pipe| if pipe.is_some() { render::GREY } else { render::BLACK });
+    let mut frames = Vec::new();
+    let step = (furthest / 50).max(1);
+    for limit in (step..=furthest + step).step_by(step) {
+        for (point, distance) in distances.iter().filter(|(_, d)| (limit - step..limit).contains(*d)) {
+            frame[*point] = render::blend(render::BLUE, render::RED, *distance as f64 / furthest as f64);
+        }
+        frames.push(frame.clone());
+    }
+
+    for point in find_enclosing_space(get_loop(&pipes, &distances)) {
+        frame[point] = render::YELLOW;
+    }
+    frames.push(frame);
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    const EXAMPLE_2: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    const EXAMPLE_3: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE_1).unwrap(), 8);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(solve_part2(EXAMPLE_2).unwrap(), 4);
+        assert_eq!(solve_part2(EXAMPLE_3).unwrap(), 8);
+    }
+
+    #[test]
+    fn rejects_a_start_without_a_loop() {
+        let error = parse("...\n.S.\n...").unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (2, 2, "S"));
+        let error = parse("F-.\nLS.\n...").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
@@ -0,0 +1,100 @@
+use common::{arith, step, Grid, Int, ParseError, SolveError};
+
+fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, |line, idx, space| {
+        match space {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(line.char_error(idx, "'.' or '#'"))
+        }
+    })
+}
+
+fn get_galaxies(image: &Grid<bool>) -> Vec<(i64, i64)> {
+    image.iter()
+        .filter(|(_, is_galaxy)| **is_galaxy)
+        .map(|((row, column), _)| (row.into(), column.into()))
+        .collect()
+}
+
+fn adjust_for_empty(image: &Grid<bool>, scale_factor: i64) -> Vec<(i64, i64)> {
+    let mut adjusted_galaxies = get_galaxies(image);
+
+    let empty_rows: Vec<i64> = (0..image.height()).filter(|r| !image.row(*r).contains(&true)).map(|r| r as i64).collect();
+    let empty_columns: Vec<i64> = (0..image.width()).filter(|c| !image.column(*c).any(|g| *g)).map(|c| c as i64).collect();
+
+    for empty_row in empty_rows.iter().rev() {
+        adjusted_galaxies = adjusted_galaxies.iter().map(|(row, column)| if *row > *empty_row { (row + scale_factor -1, *column) } else { (*row, *column) }).collect();
+    }
+
+    for empty_column in empty_columns.iter().rev() {
+        adjusted_galaxies = adjusted_galaxies.iter().map(|(row, column)| if *column > *empty_column { (*row, column + scale_factor -1) } else { (*row, *column) }).collect();
+    }
+
+    adjusted_galaxies
+}
+
+fn find_distances(galaxies: &[(i64, i64)]) -> Vec<i64> {
+    let mut distances = Vec::new();
+
+    for (row_1, column_1) in galaxies {
+        for (row_2, column_2) in galaxies {
+            let distance = (row_2 - row_1).abs() + (column_2 - column_1).abs();
+
+            if row_2 > row_1 || (row_2 == row_1 && column_2 > column_1) {
+                distances.push(distance);
+            }
+        }
+    }
+
+    distances
+}
+
+fn get_total_distance(input: &str, scale_factor: i64) -> Result<Int, SolveError> {
+    let image = parse_input(input)?;
+
+    let adjusted_galaxies = adjust_for_empty(&image, scale_factor);
+    let distances = find_distances(&adjusted_galaxies);
+    Ok(arith::sum(distances.into_iter().map(arith::widen), step!("total distance"))?)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    get_total_distance(input, 2)
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    get_total_distance(input, 1000000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 374);
+    }
+
+    #[test]
+    fn larger_expansions() {
+        assert_eq!(get_total_distance(EXAMPLE, 10).unwrap(), 1030);
+        assert_eq!(get_total_distance(EXAMPLE, 100).unwrap(), 8410);
+    }
+}
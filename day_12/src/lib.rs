@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::cell::RefCell;
+use common::{arith::{self, Overflow}, step, Int, Line, ParseError, SolveError};
+
+#[derive(PartialEq)]
+enum Status {
+    Operational,
+    Damaged,
+    Unknown  
+}
+
+impl Status {
+    fn parse(line: Line, idx: usize, c: char) -> Result<Self, ParseError> {
+        match c {
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(line.char_error(idx, "'.', '#' or '?'"))
+        }
+    }
+}
+
+struct Row {
+    pattern: Vec<Status>,
+    pattern_len: usize,
+    damaged: Vec<usize>,
+    damaged_len: usize,
+    cache: RefCell<HashMap<(usize, usize), Int>>
+}
+
+fn parse_input(input: &str, repeat: i32) -> Result<Vec<Row>, ParseError> {
+    let mut rows = Vec::new();
+
+    for row in common::lines(input) {
+        let mut parsed_pattern = Vec::new();
+        let mut parsed_damage = Vec::new();
+        for idx in 0..repeat {
+            let (pattern, damaged) = row.split_once(row.text, " ")?;
+            for (c_idx, c) in pattern.char_indices() {
+                parsed_pattern.push(Status::parse(row, c_idx, c)?);
+            }
+            for d in damaged.trim().split(",") {
+                parsed_damage.push(row.number::<usize>(d)?);
+            }
+
+            if idx != repeat -1 {
+                parsed_pattern.push(Status::Unknown);
+            }
+        }
+        let parsed_pattern_len = parsed_pattern.len();
+        let parsed_damage_len = parsed_damage.len();
+        
+
+        rows.push(Row {
+            pattern: parsed_pattern, 
+            pattern_len: parsed_pattern_len,
+            damaged: parsed_damage,
+            damaged_len: parsed_damage_len,
+            cache: RefCell::new(HashMap::new())
+        });
+    }
+
+    Ok(rows)
+}
+
+impl Row {
+    fn find_combinations(&self) -> Result<Int, Overflow> {
+        self.find_combinations_recursive(0, 0)
+    }
+
+    fn if_can_fit_damage(&self, pattern_idx: usize, damage_idx: usize) -> bool {
+        let Some(damage_count) = self.damaged.get(damage_idx) else {return false;};
+        
+        for idx in 0..*damage_count {
+            match self.pattern.get(pattern_idx + idx) {
+                None | Some(Status::Operational) => { return false; }
+                Some(Status::Damaged) | Some(Status::Unknown) => {}
+            }
+        }
+
+        match self.pattern.get(pattern_idx + *damage_count) {
+            None | Some(Status::Operational) | Some(Status::Unknown) => true,
+            Some(Status::Damaged)  => false     
+        }
+    }
+
+    fn find_combinations_recursive(&self, mut pattern_idx: usize, damage_idx: usize) -> Result<Int, Overflow> {
+        if let Some(cached) = self.cache.borrow().get(&(pattern_idx, damage_idx)) {
+            return Ok(*cached);
+        }
+
+        while let Some(Status::Operational) = self.pattern.get(pattern_idx) {
+            pattern_idx += 1;
+        }
+
+        if pattern_idx >= self.pattern_len && damage_idx == self.damaged_len {
+            return Ok(1);
+        } else if pattern_idx >= self.pattern_len {
+            return Ok(0);
+        }
+
+        let mut combinations = 0;
+
+        if self.pattern[pattern_idx] == Status::Unknown {
+            combinations = self.find_combinations_recursive(pattern_idx + 1, damage_idx)?;
+        }
+
+        let can_fit_damage = self.if_can_fit_damage(pattern_idx, damage_idx);
+        if can_fit_damage {
+            let with_damage = self.find_combinations_recursive(
+                pattern_idx + self.damaged[damage_idx] + 1, 
+                damage_idx + 1
+            )?;
+            combinations = arith::add(combinations, with_damage, step!("arrangements"))?;
+        }
+        
+        self.cache.borrow_mut().insert((pattern_idx, damage_idx), combinations);
+        Ok(combinations)
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input, 1).map(|_| ())
+}
+
+fn sum_combinations(rows: &[Row]) -> Result<Int, SolveError> {
+    let combinations = rows.iter().map(|r| r.find_combinations()).collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arith::sum(combinations, step!("total arrangements"))?)
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    sum_combinations(&parse_input(input, 1)?) // 7541
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    sum_combinations(&parse_input(input, 5)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 525152);
+    }
+
+    #[derive(Clone, Debug)]
+    struct Record {
+        pattern: Vec<char>,
+        groups: Vec<usize>
+    }
+
+    fn get_groups(springs: &[char]) -> Vec<usize> {
+        springs.split(|c| *c != '#').map(|g| g.len()).filter(|l| *l > 0).collect()
+    }
+
+    fn generate(rng: &mut Rng) -> Record {
+        let springs: Vec<char> = (0..rng.range(1..13)).map(|_| *rng.choose(&['.', '#'])).collect();
+        let pattern = springs.iter().map(|c| if rng.chance(1, 2) { '?' } else { *c }).collect();
+
+        // Usually the groups of a real arrangement, so that most records have at least one.
+        let mut groups = get_groups(&springs);
+        if groups.is_empty() || rng.chance(1, 4) {
+            groups = (0..rng.range(1..4)).map(|_| rng.range(1..4) as usize).collect();
+        }
+
+        Record { pattern, groups }
+    }
+
+    fn shrink(record: &Record) -> Vec<Record> {
+        let mut smaller = Vec::new();
+
+        for idx in 0..record.pattern.len() {
+            if record.pattern.len() > 1 {
+                let mut candidate = record.clone();
+                candidate.pattern.remove(idx);
+                smaller.push(candidate);
+            }
+            if record.pattern[idx] == '?' {
+                for c in ['.', '#'] {
+                    let mut candidate = record.clone();
+                    candidate.pattern[idx] = c;
+                    smaller.push(candidate);
+                }
+            }
+        }
+
+        for idx in 0..record.groups.len() {
+            if record.groups.len() > 1 {
+                let mut candidate = record.clone();
+                candidate.groups.remove(idx);
+                smaller.push(candidate);
+            }
+            if record.groups[idx] > 1 {
+                let mut candidate = record.clone();
+                candidate.groups[idx] -= 1;
+                smaller.push(candidate);
+            }
+        }
+
+        smaller
+    }
+
+    fn naive(record: &Record) -> Int {
+        let unknowns: Vec<usize> = (0..record.pattern.len()).filter(|idx| record.pattern[*idx] == '?').collect();
+
+        (0..1u32 << unknowns.len()).filter(|choice| {
+            let mut springs = record.pattern.clone();
+            for (bit, idx) in unknowns.iter().enumerate() {
+                springs[*idx] = if choice & (1 << bit) != 0 { '#' } else { '.' };
+            }
+            get_groups(&springs) == record.groups
+        }).count() as Int
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        common::differential(12, 500, generate, shrink,
+            |record| {
+                let groups: Vec<String> = record.groups.iter().map(|g| g.to_string()).collect();
+                let input = format!("{} {}", record.pattern.iter().collect::<String>(), groups.join(","));
+                solve_part1(&input).unwrap()
+            },
+            naive
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use common::{arith, step, Line, ParseError, SolveError, UInt};
+
+fn parse_input(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = Vec::new();
+
+    let mut current_pattern = Vec::new();
+    for row in common::lines(input) {
+        match row.text {
+            "" => { 
+                patterns.push(Pattern::parse(current_pattern, row.idx)?);
+                current_pattern = Vec::new();
+            }
+            _ => { current_pattern.push(row); }
+        }
+    }
+
+    patterns.push(Pattern::parse(current_pattern, input.lines().count())?);
+
+    Ok(patterns)
+}
+
+/// Each row and column as a bitmask with rocks set, so a pattern can be at most 64 cells each way.
+struct Pattern {
+    rows: Vec<u64>,
+    columns: Vec<u64>
+}
+
+impl Pattern {
+    fn parse(strings: Vec<Line>, end_idx: usize) -> Result<Self, ParseError> {
+        let Some(first) = strings.first() else {
+            return Err(ParseError::end_of_input(end_idx, "a pattern"));
+        };
+        let width = first.text.len();
+        if width > 64 {
+            return Err(first.error(first.text, "a row of at most 64 cells"));
+        }
+        if let Some(row) = strings.get(64) {
+            return Err(row.error(row.text, "an empty line after at most 64 rows"));
+        }
+        let digits: Vec<Vec<u64>> = strings
+            .iter()
+            .map(
+                |s| {
+                    if s.text.len() != width {
+                        return Err(s.error(s.text, &format!("a row of {} cells", width)));
+                    }
+
+                    s.text.char_indices().map(
+                        |(idx, c)| {
+                            match c {
+                                '.' => Ok(0),
+                                '#' => Ok(1),
+                                _ => Err(s.char_error(idx, "'.' or '#'"))
+                            }
+                        }
+                    ).collect()
+                }
+            ).collect::<Result<_, _>>()?;
+        
+        let rows: Vec<u64> = digits.iter().map(|r| to_bits(r.iter().copied())).collect();
+        let columns: Vec<u64> = (0..width).map(|idx| to_bits(digits.iter().map(|d| d[idx]))).collect();
+
+        Ok(Self { rows, columns })
+    }
+}
+fn to_bits(bits: impl Iterator<Item = u64>) -> u64 {
+    bits.fold(0, |value, bit| value << 1 | bit)
+}
+
+fn find_reflection(row: &[u64], required_smudges: i32) -> Option<usize> {
+    let identical: Vec<usize> = (0..(row.len() -1))
+        .map(|idx| (idx, row[idx]))
+        .filter(|(idx, value)| *value == row[idx + 1] || is_single_smudge(*value, row[idx + 1]))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    for idx in identical {
+        let mut lower_idx = idx;
+        let mut upper_idx = idx+1;
+        let mut number_of_smudges = 0;
+        loop {
+            let lower = row[lower_idx];
+            let upper = row[upper_idx];
+
+            if lower == upper {
+            } else if is_single_smudge(lower, upper) {
+                number_of_smudges += 1;
+            } else {
+                break;
+            }
+    
+            if lower_idx == 0 || upper_idx == (row.len() -1) {
+                if number_of_smudges == required_smudges {
+                    return Some(idx + 1);
+                } 
+                break;
+            }
+            lower_idx -= 1;
+            upper_idx += 1;
+        }     
+    }
+
+    None
+}
+
+fn is_single_smudge(first: u64, second: u64) -> bool {
+    (first ^ second).count_ones() <= 1
+}
+
+/// `None` unless the pattern has exactly one line of reflection.
+fn find_axis_of_reflection(pattern: &Pattern, required_smudges: i32) -> Option<UInt> {
+    match (find_reflection(&pattern.rows, required_smudges), find_reflection(&pattern.columns, required_smudges)) {
+        (None, Some(column)) => Some(column as UInt),
+        (Some(row), None) => Some(row as UInt * 100),
+        _ => None
+    }
+}
+
+fn summarise(input: &str, required_smudges: i32) -> Result<UInt, SolveError> {
+    let axes = parse_input(input)?.iter()
+        .map(|p| find_axis_of_reflection(p, required_smudges))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(SolveError::NoSolution(step!("a pattern doesn't have exactly one line of reflection")))?;
+
+    Ok(arith::sum(axes, step!("summary of notes"))?)
+}
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    summarise(input, 0) //30518
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    summarise(input, 1) //36735
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 400);
+    }
+
+    #[test]
+    fn single_smudge() {
+        assert!(is_single_smudge(0b101100110, 0b001100110));
+        assert!(is_single_smudge(0b1, 0b0));
+        assert!(!is_single_smudge(0b101100110, 0b001100111));
+        assert!(!is_single_smudge(0b11, 0b00));
+    }
+
+    #[test]
+    fn wide_patterns() {
+        let wide = format!("{0}\n{0}\n", "#.".repeat(32));
+        assert_eq!(solve_part1(&wide).unwrap(), 100);
+
+        let error = parse(&format!("{0}\n{0}\n", "#.".repeat(33))).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = parse(&"#.\n".repeat(65)).unwrap_err();
+        assert_eq!((error.line, error.column), (65, 1));
+    }
+
+    #[test]
+    fn no_single_reflection() {
+        let no_solution = Err(SolveError::NoSolution(step!("a pattern doesn't have exactly one line of reflection")));
+        assert_eq!(solve_part1("#.#\n##.\n..#\n"), no_solution);
+        assert_eq!(solve_part1("##\n##\n"), no_solution);
+    }
+}
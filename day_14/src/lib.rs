@@ -0,0 +1,95 @@
+use common::{arith::{self, Overflow}, step, Grid, Period, ParseError, SolveError, UInt};
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |line, idx, space| {
+        match space {
+            'O' | '#' | '.' => Ok(space),
+            _ => Err(line.char_error(idx, "'O', '#' or '.'"))
+        }
+    })
+}
+
+fn tilt_north(platform: &Grid<char>) -> Grid<char> {
+    let mut new_platform = platform.clone();
+    for column_idx in 0..platform.width() as i32 {
+        let mut next_available = 0;
+
+        for row_idx in 0..platform.height() as i32 {
+            match platform[(row_idx, column_idx)] {
+                '#' => { next_available = row_idx + 1; },
+                'O' => {
+                    new_platform[(row_idx, column_idx)] = '.';
+                    new_platform[(next_available, column_idx)] = 'O';
+                    next_available += 1;
+                },
+                _ => {}
+            }
+        }
+    }
+
+    new_platform
+}
+
+// Tilting north then turning clockwise leaves west at the top, so four rounds of that is one
+// north, west, south, east cycle and brings the platform back to its starting orientation.
+fn spin_cycle(platform: &Grid<char>) -> Grid<char> {
+    let mut after_cycle = platform.clone();
+    for _ in 0..4 {
+        after_cycle = tilt_north(&after_cycle).rotate_clockwise();
+    }
+
+    after_cycle
+}
+
+fn get_load(platform: &Grid<char>) -> Result<UInt, Overflow> {
+    arith::sum(platform.iter()
+        .filter(|(_, space)| **space == 'O')
+        .map(|((row_idx, _), _)| (platform.height() - row_idx as usize) as UInt), step!("total load"))
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    let platform = parse_input(input)?;
+
+    Ok(get_load(&tilt_north(&platform))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    let platform = parse_input(input)?;
+
+    let period = Period::brent(&platform, spin_cycle);
+    let spun = (0..period.reduce(1_000_000_000)).fold(platform, |platform, _| spin_cycle(&platform));
+
+    Ok(get_load(&spun)?) // 102055
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 64);
+    }
+}
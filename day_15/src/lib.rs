@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use common::{arith::{self, Overflow}, step, Line, ParseError, SolveError, UInt};
+
+fn parse_input(input: &str) -> Vec<&str> {
+    input.trim().split(",").collect()
+}
+
+fn get_hash(s: &str) -> u32 {
+    let mut value = 0;
+
+    for c in s.chars() {
+        let ascii_value = c as u32;
+        value += ascii_value;
+        value *= 17;
+        value %= 256;
+    }
+
+    value
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Add(String, u32),
+    Remove(String)
+}
+
+impl Instruction {
+    fn parse(line: Line, input: &str) -> Result<Self, ParseError> {
+        if let Some((label, value)) = input.split_once("=") {
+            Ok(Self::Add(label.to_string(), line.number(value)?))
+        } else if let Some((label, _)) = input.split_once("-") {
+            Ok(Self::Remove(label.to_string()))
+        } else {
+            Err(line.error(input, "'<label>=<focal length>' or '<label>-'"))
+        }
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+
+    for line in common::lines(input.trim()) {
+        for step in line.text.split(",") {
+            instructions.push(Instruction::parse(line, step)?);
+        }
+    }
+
+    Ok(instructions)
+}
+
+fn process(instructions: &[Instruction]) -> Result<UInt, Overflow> {
+    let mut boxes: HashMap<u32, Vec<(String, u32)>> = HashMap::new();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Remove(label) => {
+                let hash = get_hash(label);
+                if let Some(b) = boxes.get_mut(&hash) {
+                    *b = (*b).iter()
+                        .filter(|l| l.0 != *label)
+                        .map(|l| l.to_owned())
+                        .collect();
+                }
+            },
+            Instruction::Add(label, value) => {
+                let hash = get_hash(label);
+                if let Some(b) = boxes.get_mut(&hash) {
+                    let mut updated = false;
+                    *b = (*b).iter()
+                        .map(|l| {
+                            if l.0 == *label {
+                                updated = true;
+                                (l.0.to_owned(), *value)
+                            } else {
+                                l.to_owned()
+                            }
+                        })
+                        .collect();
+
+                    if !updated {
+                        (*b).push((label.to_owned(), *value));
+                    }
+                } else {
+                    boxes.insert(hash, Vec::from([(label.to_owned(), *value)]));
+                }
+            }
+            
+        }
+    }
+
+    let mut total = 0;
+    for (box_idx, lenses) in boxes {
+        let box_value = UInt::from(box_idx + 1);
+        for (lens_idx, (_, value))  in lenses.iter().enumerate() {
+            let lens_value = (lens_idx + 1) as UInt;
+            let power = arith::product([box_value, lens_value, UInt::from(*value)], step!("focusing power"))?;
+            total = arith::add(total, power, step!("total focusing power"))?;
+        }
+    }
+
+    Ok(total)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_instructions(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    Ok(arith::sum(parse_input(input).iter().map(|s| UInt::from(get_hash(s))), step!("sum of hashes"))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    Ok(process(&parse_instructions(input)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 1320);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 145);
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::{HashMap, HashSet};
+use common::{render::{self, Colour}, Grid, ParseError};
+
+#[derive(Clone, Debug)]
+enum Direction {
+    Up(Option<(i32, i32)>),
+    Down(Option<(i32, i32)>),
+    Left(Option<(i32, i32)>),
+    Right(Option<(i32, i32)>)
+}
+
+type Points = HashMap<(i32, i32), Point>;
+
+#[derive(Debug)]
+struct Point {
+    going_up: Vec<Direction>,
+    going_down: Vec<Direction>,
+    going_left: Vec<Direction>,
+    going_right: Vec<Direction>
+}
+
+fn parse_input(input: &str) -> Result<(Grid<char>, Points), ParseError>  {
+    let mirrors = Grid::parse(input, |line, column_idx, space| {
+        match space {
+            '.' | '/' | '\\' | '|' | '-' => Ok(space),
+            _ => Err(line.char_error(column_idx, "'.', '/', '\\', '|' or '-'"))
+        }
+    })?;
+
+    let points = parse_points(&mirrors);
+    Ok((mirrors, points))
+}
+
+fn find_mirror(from: (i32, i32), step: (i32, i32), mirrors: &Grid<char>) -> Option<(i32, i32)> {
+    let mut point = (from.0 + step.0, from.1 + step.1);
+    while let Some(space) = mirrors.get(point) {
+        if *space != '.' {
+            return Some(point);
+        }
+        point = (point.0 + step.0, point.1 + step.1);
+    }
+
+    None
+}
+
+fn parse_points(mirrors: &Grid<char>) -> Points {
+    let mut points = HashMap::new();
+
+    for (coords, mirror) in mirrors.iter().filter(|(_, space)| **space != '.') {
+        let mirror_above = Direction::Up(find_mirror(coords, (-1, 0), mirrors));
+        let mirror_below = Direction::Down(find_mirror(coords, (1, 0), mirrors));
+        let mirror_left = Direction::Left(find_mirror(coords, (0, -1), mirrors));
+        let mirror_right = Direction::Right(find_mirror(coords, (0, 1), mirrors));
+
+        let point = match mirror {
+            '/' => {
+                Point { 
+                    going_up: vec![mirror_right],
+                    going_down: vec![mirror_left],
+                    going_left: vec![mirror_below],
+                    going_right: vec![mirror_above]
+                }
+            },
+            '\\' => {
+                Point { 
+                    going_up: vec![mirror_left],
+                    going_down:vec![mirror_right],
+                    going_left:vec![mirror_above],
+                    going_right:vec![mirror_below]
+                }
+            },
+            '|' => {
+                Point { 
+                    going_up: vec![mirror_above.clone()],
+                    going_down:vec![mirror_below.clone()],
+                    going_left:vec![mirror_above.clone(), mirror_below.clone()],
+                    going_right:vec![mirror_above, mirror_below]
+                }
+            },
+            '-' => {
+                Point { 
+                    going_up: vec![mirror_left.clone(), mirror_right.clone()],
+                    going_down:vec![mirror_left.clone(), mirror_right.clone()],
+                    going_left:vec![mirror_left],
+                    going_right:vec![mirror_right]
+                }
+            },
+            _ => panic!("Oh No!")
+        };
+
+        points.insert(coords, point);
+    }
+
+    points
+}
+
+impl Direction {
+    fn extract(&self) -> Option<(i32, i32)> {
+        match self {
+            Direction::Up(p) => p.to_owned(),
+            Direction::Down(p) => p.to_owned(),
+            Direction::Left(p) => p.to_owned(),
+            Direction::Right(p) => p.to_owned(),
+        }
+    }
+
+
+    fn get_edge(&self, from: &(i32, i32), mirrors: &Grid<char>) -> (i32, i32) {
+        let (r,c) = from;
+        let (height, width) = (mirrors.height() as i32, mirrors.width() as i32);
+        match self {
+            Direction::Up(None) => (0, *c),
+            Direction::Down(None) => (height -1 , *c),
+            Direction::Left(None) => (*r, 0),
+            Direction::Right(None) => (*r, width - 1),
+            _ => panic!("")
+        }
+    }
+}
+
+fn walk(start: Direction, mirrors: &Grid<char>, points: &Points) -> HashSet<((i32, i32), (i32, i32))> {
+    let mut to_go = Vec::from([start]);
+    let mut seen = HashSet::new();
+
+    while let Some(current) = to_go.pop() {
+        let next_steps = match &current {
+            Direction::Up(Some(point)) => &points.get(point).unwrap().going_up,
+            Direction::Down(Some(point)) => &points.get(point).unwrap().going_down,
+            Direction::Left(Some(point)) => &points.get(point).unwrap().going_left,
+            Direction::Right(Some(point)) => &points.get(point).unwrap().going_right,
+            _ => panic!("Oh No!")
+        };
+
+        let Some(current_coord) = current.extract() else { panic!(""); };
+
+        for next in next_steps {
+            if let Some(next_coord) = next.extract() {
+                let direction = (current_coord.to_owned(), next_coord);
+                if !seen.contains(&direction) {
+                    seen.insert(direction);
+                    to_go.push(next.to_owned());
+                }
+
+            } else {
+                let edge = next.get_edge(&current_coord, mirrors);
+                seen.insert((current_coord.to_owned(), edge));
+            }
+        }
+    }
+
+    seen
+}
+
+
+fn get_first_mirror(start: Direction, mirrors: &Grid<char>, points: &Points) -> (Option<Direction>, HashSet<(i32, i32)>){
+    let mut curr = start;
+    let mut seen = HashSet::new();
+    loop {
+        let coords = curr.extract().unwrap();
+
+        if !mirrors.contains(coords) {
+            return (None, seen);
+        }
+
+        if points.contains_key(&coords) {
+            break;
+        }
+
+        curr = match curr {
+            Direction::Up(Some((r,c))) => Direction::Up(Some((r-1,c))),  
+            Direction::Down(Some((r,c))) => Direction::Down(Some((r+1,c))), 
+            Direction::Left(Some((r,c))) => Direction::Left(Some((r,c-1))), 
+            Direction::Right(Some((r,c))) => Direction::Right(Some((r,c+1))), 
+            _ => panic!("Oh No!")
+        };
+
+        seen.insert(coords);
+    }
+
+    (Some(curr), seen)
+}
+
+
+fn get_energized(start: Direction, mirrors: &Grid<char>, points: &Points) -> HashSet<(i32, i32)> {
+    let (first_mirror, mut energized) = get_first_mirror(start, mirrors, points);
+    let Some(first_mirror) = first_mirror else {
+        return energized;
+    };
+    let walked_to = walk(first_mirror, mirrors, points);
+
+    for ((start_row, start_col), (end_row, end_col)) in walked_to.iter() {
+        if *start_row == *end_row {
+            let(start, end) = if *start_col > *end_col {
+                (*end_col, *start_col)
+            } else {
+                (*start_col, *end_col)
+            };
+            for column_idx in start..=end {
+                energized.insert((*start_row, column_idx));
+            }
+        } else if *start_col == *end_col {
+            let (start,end) = if *start_row > *end_row {
+                (*end_row, *start_row)
+            } else {
+                (*start_row, *end_row)
+            };
+            for row_idx in start..=end {
+                energized.insert((row_idx, *start_col));
+            }
+        } else {
+            panic!("Oh No!")
+        }
+    }
+
+    energized
+}
+
+fn count_energized(start: Direction, mirrors: &Grid<char>, points: &Points) -> usize {
+    get_energized(start, mirrors, points).len()
+}
+
+
+fn get_all_starts(mirrors: &Grid<char>) -> Vec<Direction> {
+    let mut starts = Vec::new();
+    let (height, width) = (mirrors.height() as i32, mirrors.width() as i32);
+
+    for idx in 0..width {
+        starts.push(Direction::Up(Some((height - 1, idx))));
+        starts.push(Direction::Down(Some((0, idx))));
+    }
+
+    for idx in 0..height {
+        starts.push(Direction::Left(Some((idx, width - 1))));
+        starts.push(Direction::Right(Some((idx, 0))));
+    }
+
+    starts
+}
+
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let (mirrors, points) = parse_input(input)?;
+
+    Ok(count_energized(Direction::Right(Some((0,0))), &mirrors, &points)) // 7199
+}
+
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let (mirrors, points) = parse_input(input)?;
+
+    let start_points = get_all_starts(&mirrors);
+
+    Ok(start_points.iter().map(|s| count_energized(s.to_owned(), &mirrors, &points)).max().unwrap()) // 7438
+}
+
+/// The contraption with the tiles energized by the part 1 beam lit up.
+pub fn render(input: &str) -> Result<Vec<Grid<Colour>>, ParseError> {
+    let (mirrors, points) = parse_input(input)?;
+
+    let mut frame = mirrors.map(|space| if *space == '.' { render::BLACK } else { render::GREY });
+    for point in get_energized(Direction::Right(Some((0,0))), &mirrors, &points) {
+        frame[point] = if mirrors[point] == '.' { render::YELLOW } else { render::WHITE };
+    }
+
+    Ok(vec![frame])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 51);
+    }
+}
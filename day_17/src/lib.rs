@@ -0,0 +1,200 @@
+use common::{graph, render::{self, Colour}, step, Grid, ParseError, Point, SolveError};
+
+fn parse_input(input: &str) -> Result<Map, ParseError>  {
+    let blocks = Grid::parse(input, |line, column_idx, space| {
+        match space.to_digit(10) {
+            Some(value) => Ok(value.try_into().unwrap()),
+            None => Err(line.char_error(column_idx, "a digit"))
+        }
+    })?;
+
+    Ok(Map { blocks })
+}
+
+struct Map {
+    blocks: Grid<i32>
+}
+
+#[derive(PartialEq, Hash, Eq, Clone)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+#[derive(PartialEq, Hash, Eq, Clone)]
+struct Node {
+    pos: Point,
+    direction: Direction,
+    current_steps: i32
+}
+
+impl Direction {
+    fn step(&self, current: &Point, blocks: &Grid<i32>) -> Option<Point> {
+        let (row, col) = *current;
+        let next = match self {
+            Self::Up => (row - 1, col),
+            Self::Down => (row + 1, col),
+            Self::Left => (row, col - 1),
+            Self::Right => (row, col + 1),
+        };
+
+        blocks.contains(next).then_some(next)
+    }
+
+    fn turn_right(&self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+            Self::Right => Self::Down,
+        }
+    }
+
+    fn turn_left(&self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+            Self::Right => Self::Up,
+        }
+    }
+}
+
+impl Node {
+    fn get_neighbours(&self, min_step: i32, max_step: i32, blocks: &Grid<i32>) -> Vec<Self> {
+        let mut neigbours = Vec::new();
+        if self.current_steps < max_step {
+            if let Some(new) = self.direction.step(&self.pos, blocks) {
+                neigbours.push(Node { pos: new, direction: self.direction.clone(), current_steps: self.current_steps + 1 })
+            }
+        }
+        if self.current_steps >= min_step {
+            let left_direction = self.direction.turn_left();
+            if let Some(new) = left_direction.step(&self.pos, blocks) {
+                neigbours.push(Node { pos: new, direction: left_direction, current_steps: 1 })
+            }
+            let right_direction = self.direction.turn_right();
+            if let Some(new) = right_direction.step(&self.pos, blocks) {
+                neigbours.push(Node { pos: new, direction: right_direction, current_steps: 1 })
+            }
+        }
+
+        neigbours
+    }
+
+    fn is_end(&self, min_step: i32, end: Point) -> bool {
+        self.pos == end && self.current_steps >= min_step
+    }
+}
+
+impl Map {
+    /// The least heat lost getting from the top left to the bottom right, and the route taken, or
+    /// `None` if the crucible can't get there moving at least `min_step` blocks at a time.
+    fn walk(&self, min_step: i32, max_step: i32) -> Option<(i32, Vec<Point>)> {
+        let end = (self.blocks.height() as i32 - 1, self.blocks.width() as i32 - 1);
+        let starts = [Direction::Right, Direction::Down].map(|direction| Node { pos: (0,0), direction, current_steps: 0 });
+
+        let (heat_loss, route) = graph::dijkstra(
+            starts,
+            |node| node.get_neighbours(min_step, max_step, &self.blocks).into_iter().map(|n| {
+                let loss = self.blocks[n.pos];
+                (n, loss)
+            }),
+            |node| node.is_end(min_step, end)
+        )?;
+
+        Some((heat_loss, route.iter().map(|node| node.pos).collect()))
+    }
+
+    fn least_heat_loss(&self, min_step: i32, max_step: i32) -> Result<i32, SolveError> {
+        self.walk(min_step, max_step)
+            .map(|(heat_loss, _)| heat_loss)
+            .ok_or(SolveError::NoSolution(step!("there is no route to the bottom right")))
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<i32, SolveError> {
+    parse_input(input)?.least_heat_loss(1, 3)
+}
+
+pub fn solve_part2(input: &str) -> Result<i32, SolveError> {
+    parse_input(input)?.least_heat_loss(4, 10)
+}
+
+/// The city shaded by heat loss, with the part 1 route in one frame and the part 2 route in the next.
+/// A part without a route gets a frame of just the city.
+pub fn render(input: &str) -> Result<Vec<Grid<Colour>>, ParseError> {
+    let map = parse_input(input)?;
+    let city = map.blocks.map(|loss| render::blend(render::BLACK, render::GREY, f64::from(*loss) / 9.0));
+
+    Ok([(1, 3), (4, 10)].iter().map(|(min_step, max_step)| {
+        let mut frame = city.clone();
+        for point in map.walk(*min_step, *max_step).map_or(Vec::new(), |(_, route)| route) {
+            frame[point] = render::RED;
+        }
+        frame
+    }).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    const EXAMPLE_2: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE_1).unwrap(), 102);
+    }
+
+    #[test]
+    fn route_loses_the_reported_heat() {
+        let map = parse_input(EXAMPLE_1).unwrap();
+        let (heat_loss, route) = map.walk(1, 3).unwrap();
+        assert_eq!(route.first(), Some(&(0, 0)));
+        assert_eq!(route.last(), Some(&(12, 12)));
+        assert_eq!(route[1..].iter().map(|p| map.blocks[*p]).sum::<i32>(), heat_loss);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(solve_part2(EXAMPLE_1).unwrap(), 94);
+        assert_eq!(solve_part2(EXAMPLE_2).unwrap(), 71);
+    }
+
+    #[test]
+    fn no_route() {
+        let no_solution = Err(SolveError::NoSolution(step!("there is no route to the bottom right")));
+        assert_eq!(solve_part1("5"), no_solution);
+        assert_eq!(solve_part2("123"), no_solution);
+        assert_eq!(solve_part1("123").unwrap(), 5);
+    }
+}
@@ -0,0 +1,375 @@
+use std::collections::HashSet;
+use common::{arith::{self, Overflow}, step, Int, Line, ParseError, SolveError};
+
+fn parse_input(input: &str, from_colour: bool) -> Result<Vec<Instruction>, ParseError>  {
+    let mut instructions = Vec::new();
+    for row in common::lines(input) {
+        let [direction, count, colour] = row.text.split_whitespace().collect::<Vec<&str>>()[..] else {
+            return Err(row.error(row.text, "a direction, count and colour"));
+        };
+
+        if from_colour {
+            instructions.push(get_hexadecimal_instr(row, colour)?);
+        } else {
+            instructions.push(Instruction {
+                direction: Direction::parse(row, direction)?,
+                count: row.number(count)?
+            });
+        }
+    }
+
+    Ok(instructions)
+}
+
+#[derive(PartialEq, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+struct Instruction {
+    direction: Direction,
+    count: i64
+}
+
+#[derive(Debug)]
+struct VerticalEdge {
+    direction: Direction,
+    col_idx: i64,
+    start_row: i64,
+    end_row: i64
+}
+
+impl Direction {
+    fn parse(line: Line, direction: &str) -> Result<Self, ParseError> {
+        match direction {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(line.error(direction, "'U', 'D', 'L' or 'R'"))
+        }
+    }
+
+    fn step(&self, current: &(i64, i64), count: i64) -> (i64, i64) {
+        let (row, col) = *current;
+        match self {
+            Self::Up => (row - count, col),
+            Self::Down => (row + count, col),
+            Self::Left => (row, col - count),
+            Self::Right => (row, col + count),
+        }
+    }
+}
+
+impl VerticalEdge {
+    fn is_corner(&self, point: i64) -> bool {
+        point == self.start_row || point == self.end_row
+    }
+}
+
+fn get_hexadecimal_instr(line: Line, colour: &str) -> Result<Instruction, ParseError> {
+    let hex = colour.trim_end_matches(')').trim_start_matches(['(', '#']);
+
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(line.error(colour, "'(#' followed by six hexadecimal digits and ')'"));
+    }
+
+    let (count_str, dir_str) = hex.split_at(5);
+    let direction = match dir_str {
+        "3" => Direction::Up,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "0" => Direction::Right,
+        _ => return Err(line.error(dir_str, "a direction digit from 0 to 3"))
+    };
+
+    let Ok(count) = i64::from_str_radix(count_str, 16) else {
+        return Err(line.error(count_str, "a hexadecimal number"));
+    };
+
+    Ok(Instruction { direction, count })
+}
+
+fn get_vertical_edges(instructions: &[Instruction]) -> Vec<VerticalEdge> {
+    let mut curr = (0,0);
+    let mut edges = vec![];
+
+    for instr in instructions {
+        let (row_1, col_1) = curr;
+        let (row_2, col_2) = instr.direction.step(&curr, instr.count);
+        match instr.direction {
+            Direction::Up => { edges.push(VerticalEdge { direction: Direction::Up, col_idx: col_1, start_row: row_2, end_row: row_1 }) },
+            Direction::Down => { edges.push(VerticalEdge { direction: Direction::Down, col_idx: col_1, start_row: row_1, end_row: row_2 }) },
+            _ => {}
+        }
+        
+        curr=(row_2, col_2);
+    }
+
+    edges
+}
+
+fn get_partitions(edges: &[VerticalEdge]) -> Vec<(i64, i64)> {
+    let all_start_rows: HashSet<i64> = edges.iter().map(|e| e.start_row).collect();
+    let all_end_rows: HashSet<i64> = edges.iter().map(|e| e.end_row).collect();
+    let mut all_corner_rows: Vec<i64> = all_start_rows.union(&all_end_rows).copied().collect();
+    all_corner_rows.sort();
+
+    let mut partitions = Vec::new();
+
+    let mut last_corner_row = 0;
+    for (i, corner_row) in all_corner_rows.iter().enumerate() {
+        if i == 0 {
+            partitions.push((*corner_row, *corner_row));
+            last_corner_row = *corner_row;
+        } else {
+            if corner_row - 1 != last_corner_row {
+                partitions.push((last_corner_row + 1, corner_row - 1));
+            }
+
+            partitions.push((*corner_row, *corner_row));
+            last_corner_row = *corner_row;
+        }
+    }
+
+    partitions
+}
+
+
+fn count(edges: &[VerticalEdge]) -> Result<Int, Overflow> {
+    let mut total = 0;
+
+    for (start, end) in get_partitions(edges) {
+        let mut relevant_edges: Vec<&VerticalEdge> = edges.iter()
+            .filter(|e| e.start_row <= start && start <= e.end_row)
+            .collect();
+        relevant_edges.sort_by_key(|k| k.col_idx);
+
+        let mut count = 1;
+        let mut last_edge = relevant_edges[0];
+        let mut inside = !last_edge.is_corner(start);
+        let mut on_corner = last_edge.is_corner(start);
+
+        for edge in relevant_edges[1..].iter() {
+            count += 1;
+
+            if inside || on_corner {
+                count += edge.col_idx - last_edge.col_idx -1;
+            }
+
+            if !edge.is_corner(start) {
+                assert!(!on_corner);
+                inside  = !inside;
+            } else {
+                if on_corner && last_edge.direction == edge.direction {
+                    inside = !inside;
+                }
+                on_corner = !on_corner;
+            }
+            last_edge = edge;
+        }
+        let area = arith::mul(arith::widen(count), arith::widen(end - start + 1), step!("area of rows"))?;
+        total = arith::add(total, area, step!("lagoon area"))?;
+    }
+
+    Ok(total)
+}
+
+
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input, false).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    let instructions = parse_input(input, false)?;
+
+    Ok(count(&get_vertical_edges(&instructions))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let hex_instructions = parse_input(input, true)?;
+
+    Ok(count(&get_vertical_edges(&hex_instructions))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 62);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 952408144115);
+    }
+
+    /// A lagoon made of side by side columns, each `width` wide and running from `top` down to
+    /// `bottom`; neighbouring columns overlap so the trench never touches itself. The outline is
+    /// then rotated by quarter turns and optionally walked the other way round.
+    #[derive(Clone, Debug)]
+    struct Lagoon {
+        columns: Vec<(i64, i64, i64)>,
+        turns: usize,
+        reversed: bool
+    }
+
+    impl Lagoon {
+        fn is_valid(&self) -> bool {
+            !self.columns.is_empty()
+                && self.columns.iter().all(|(width, top, bottom)| *width > 0 && top < bottom)
+                && self.columns.windows(2).all(|w| w[0].1.max(w[1].1) < w[0].2.min(w[1].2))
+        }
+
+        fn moves(&self) -> Vec<(char, i64)> {
+            let mut moves = Vec::new();
+            let mut push = |direction: char, count: i64| {
+                match moves.last_mut() {
+                    _ if count == 0 => {}
+                    Some((d, c)) if *d == direction => *c += count,
+                    _ => moves.push((direction, count))
+                }
+            };
+
+            let vertical = |from: i64, to: i64| if to < from { ('U', from - to) } else { ('D', to - from) };
+            let (_, first_top, first_bottom) = self.columns[0];
+            let (_, last_top, last_bottom) = self.columns[self.columns.len() - 1];
+
+            for (idx, (width, top, _)) in self.columns.iter().enumerate() {
+                if idx > 0 {
+                    let (direction, count) = vertical(self.columns[idx - 1].1, *top);
+                    push(direction, count);
+                }
+                push('R', *width);
+            }
+            push('D', last_bottom - last_top);
+            for (idx, (width, _, bottom)) in self.columns.iter().enumerate().rev() {
+                if idx + 1 < self.columns.len() {
+                    let (direction, count) = vertical(self.columns[idx + 1].2, *bottom);
+                    push(direction, count);
+                }
+                push('L', *width);
+            }
+            push('U', first_bottom - first_top);
+
+            let order = ['R', 'D', 'L', 'U'];
+            let turn = |direction: char, turns: usize| {
+                order[(order.iter().position(|d| *d == direction).unwrap() + turns) % 4]
+            };
+
+            let mut moves: Vec<(char, i64)> = moves.into_iter().map(|(d, c)| (turn(d, self.turns), c)).collect();
+            if self.reversed {
+                moves = moves.into_iter().rev().map(|(d, c)| (turn(d, 2), c)).collect();
+            }
+
+            moves
+        }
+
+        fn render(&self) -> String {
+            self.moves().iter().map(|(direction, count)| {
+                let digit = "RDLU".find(*direction).unwrap();
+                format!("{} {} (#{:05x}{})\n", direction, count, count, digit)
+            }).collect()
+        }
+    }
+
+    fn generate(rng: &mut Rng) -> Lagoon {
+        let tops: Vec<i64> = (0..rng.range(1..6)).map(|_| rng.range(0..5)).collect();
+        let columns = (0..tops.len()).map(|idx| {
+            let neighbours = &tops[idx.saturating_sub(1)..(idx + 2).min(tops.len())];
+            let bottom = neighbours.iter().max().unwrap() + rng.range(1..5);
+            (rng.range(1..4), tops[idx], bottom)
+        }).collect();
+
+        Lagoon { columns, turns: rng.index(4), reversed: rng.chance(1, 2) }
+    }
+
+    fn shrink(lagoon: &Lagoon) -> Vec<Lagoon> {
+        let mut smaller = Vec::new();
+
+        for idx in 0..lagoon.columns.len() {
+            let mut candidate = lagoon.clone();
+            candidate.columns.remove(idx);
+            smaller.push(candidate);
+
+            let (width, top, bottom) = lagoon.columns[idx];
+            for column in [(width - 1, top, bottom), (width, top + 1, bottom), (width, top, bottom - 1)] {
+                let mut candidate = lagoon.clone();
+                candidate.columns[idx] = column;
+                smaller.push(candidate);
+            }
+        }
+        if lagoon.turns > 0 || lagoon.reversed {
+            smaller.push(Lagoon { turns: 0, reversed: false, ..lagoon.clone() });
+        }
+
+        smaller.into_iter().filter(|l| l.is_valid()).collect()
+    }
+
+    /// Digs out the trench cell by cell, then counts everything that can't be reached from outside.
+    fn naive(lagoon: &Lagoon) -> (Int, Int) {
+        let mut trench = HashSet::from([(0, 0)]);
+        let mut current = (0, 0);
+        for (direction, count) in lagoon.moves() {
+            let step = match direction { 'U' => (-1, 0), 'D' => (1, 0), 'L' => (0, -1), _ => (0, 1) };
+            for _ in 0..count {
+                current = (current.0 + step.0, current.1 + step.1);
+                trench.insert(current);
+            }
+        }
+
+        let min_row = trench.iter().map(|p| p.0).min().unwrap() - 1;
+        let max_row = trench.iter().map(|p| p.0).max().unwrap() + 1;
+        let min_col = trench.iter().map(|p| p.1).min().unwrap() - 1;
+        let max_col = trench.iter().map(|p| p.1).max().unwrap() + 1;
+
+        let mut outside = HashSet::from([(min_row, min_col)]);
+        let mut stack = vec![(min_row, min_col)];
+        while let Some((row, col)) = stack.pop() {
+            for next in [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)] {
+                let in_box = (min_row..=max_row).contains(&next.0) && (min_col..=max_col).contains(&next.1);
+                if in_box && !trench.contains(&next) && outside.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        let area = arith::widen((max_row - min_row + 1) * (max_col - min_col + 1) - outside.len() as i64);
+        (area, area)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        common::differential(18, 500, generate, shrink,
+            |lagoon| {
+                let input = lagoon.render();
+                (solve_part1(&input).unwrap(), solve_part2(&input).unwrap())
+            },
+            naive
+        );
+    }
+}
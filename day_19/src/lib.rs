@@ -0,0 +1,471 @@
+use std::collections::HashMap;
+use common::{arith, step, Int, Interval, Line, ParseError, SolveError};
+
+type Workflows = HashMap<String, Vec<(Option<Condition>, Branch)>>;
+
+fn parse_input(input: &str) -> Result<(Workflows, Vec<Item>), ParseError>  {
+    let mut workflows = HashMap::new();
+    let mut items = Vec::new();
+    let mut targets = Vec::new();
+
+    let mut lines = common::lines(input);
+    let mut row = lines.expect("a workflow")?;
+    while !row.text.is_empty() {
+        let (instruction_id, code) = row.split_once(row.text, "{")?;
+        let mut conditions = Vec::new();
+        for branch in code.trim_end_matches("}").split(",") {
+            let (condition, result) = match branch.split_once(":") {
+                Some((condition, result)) => (Some(Condition::parse(row, condition)?), result),
+                None => (None, branch)
+            };
+            if !matches!(result, "A" | "R") {
+                targets.push((row, result));
+            }
+            conditions.push((condition, Branch::parse(result)))
+        }
+        if let Some((Some(_), _)) = conditions.last() {
+            let last = code.trim_end_matches("}").rsplit(",").next().unwrap_or(code);
+            return Err(row.error(last, "a fallback branch without a condition at the end"));
+        }
+
+        workflows.insert(instruction_id.to_owned(), conditions);
+
+        row = lines.expect("a workflow or an empty line")?;
+    }
+
+    if !workflows.contains_key("in") {
+        return Err(row.error(row.text, "a workflow named 'in' before the ratings"));
+    }
+    if let Some((line, target)) = targets.into_iter().find(|(_, target)| !workflows.contains_key(*target)) {
+        return Err(line.error(target, "'A', 'R' or the name of a workflow"));
+    }
+    for row in lines {
+        items.push(Item::parse(row, row.text.trim_matches('{').trim_matches('}').split(",").collect())?)
+    }
+
+    Ok((workflows, items))
+}
+
+#[derive(Debug)]
+struct Item {
+    x: i64,
+    m: i64,
+    a: i64,
+    s: i64 
+}
+
+impl Item {
+    fn parse(line: Line, attributes: Vec<&str>) -> Result<Self, ParseError> {
+        let mut attribute_map:HashMap<&str, i64> = HashMap::new();
+        for a in attributes {
+            let (name, value) = line.split_once(a, "=")?;
+            attribute_map.insert(name, line.number(value)?);
+        }
+
+        let get = |name: &str| attribute_map.get(name).copied().ok_or_else(
+            || line.error(line.text, &format!("a value for '{}'", name))
+        );
+
+        Ok(Self {
+            x: get("x")?,
+            m: get("m")?,
+            a: get("a")?,
+            s: get("s")?
+        })
+    }
+
+    fn value(&self) -> i64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Debug)]
+enum Comparison {
+    GreaterThan,
+    LessThan
+}
+
+impl Comparison {
+    fn parse(line: Line, comparison: &str) -> Result<Self, ParseError> {
+        match comparison {
+            ">" => Ok(Self::GreaterThan),
+            "<" => Ok(Self::LessThan),
+            _ => Err(line.error(comparison, "'<' or '>'"))
+        }
+    }
+
+    fn evaluate(&self, item_value: i64, other_value: i64) -> bool {
+        match self {
+            Self::GreaterThan => item_value > other_value,
+            Self::LessThan => item_value < other_value
+        }
+    }
+
+    /// Splits `limit` into the values that pass the comparison against `value` and those that don't.
+    fn split(&self, limit: &Interval, value: i64) -> (Interval, Interval) {
+        match self {
+            Self::GreaterThan => {
+                let (rejected, passed) = limit.split_at(value + 1);
+                (passed, rejected)
+            },
+            Self::LessThan => limit.split_at(value)
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Condition {
+    X(Comparison, i64),
+    M(Comparison, i64),
+    A(Comparison, i64),
+    S(Comparison, i64),
+}
+
+impl Condition {
+    fn parse(line: Line, text: &str) -> Result<Self, ParseError> {
+        let (Some(attribute), Some(comparison), Some(value)) = (text.get(..1), text.get(1..2), text.get(2..)) else {
+            return Err(line.error(text, "a condition such as 'x<100'"));
+        };
+        let condition = Comparison::parse(line, comparison)?;
+        let value: i64 = line.number(value)?;
+
+        match attribute {
+            "x" => Ok(Self::X(condition, value)),
+            "m" => Ok(Self::M(condition, value)),
+            "a" => Ok(Self::A(condition, value)),
+            "s" => Ok(Self::S(condition, value)),
+            _ => Err(line.error(attribute, "'x', 'm', 'a' or 's'"))
+        }
+    }
+
+    fn evaluate(&self, item: &Item) -> bool {
+        match self {
+            Self::X(cond, value) => cond.evaluate(item.x, *value),
+            Self::M(cond, value) => cond.evaluate(item.m, *value),
+            Self::A(cond, value) => cond.evaluate(item.a, *value),
+            Self::S(cond, value) => cond.evaluate(item.s, *value),
+        }
+    }
+
+    fn limit<'a>(&self, l: &'a mut AttributeLimit) -> &'a mut Interval {
+        match self {
+            Self::X(..) => &mut l.x,
+            Self::M(..) => &mut l.m,
+            Self::A(..) => &mut l.a,
+            Self::S(..) => &mut l.s,
+        }
+    }
+
+    fn update(&self, l: &AttributeLimit) -> (AttributeLimit, AttributeLimit) {
+        let (Self::X(cond, value) | Self::M(cond, value) | Self::A(cond, value) | Self::S(cond, value)) = self;
+        let (mut passed, mut rejected) = (l.clone(), l.clone());
+        let (passed_limit, rejected_limit) = cond.split(self.limit(&mut passed), *value);
+
+        *self.limit(&mut passed) = passed_limit;
+        *self.limit(&mut rejected) = rejected_limit;
+        (passed, rejected)
+    }
+}
+
+#[derive(Debug)]
+enum Branch {
+    Instruction(String),
+    Accept,
+    Reject
+}
+
+impl Branch {
+    fn parse(line: &str) -> Self {
+        match line {
+            "R" => Self::Reject,
+            "A" => Self::Accept,
+            a => Self::Instruction(a.to_owned())
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AttributeLimit {
+    x: Interval,
+    m: Interval,
+    a: Interval,
+    s: Interval
+}
+
+fn evaluate_workflow<'a>(workflow: &'a [(Option<Condition>, Branch)], item: &Item) -> &'a Branch {
+    for (condition, branch) in workflow {
+        if let Some(cond) = condition {
+            if cond.evaluate(item) {
+                return branch;
+            }
+        } else {
+            return branch;
+        }
+    }
+
+    unreachable!("parse_input checks that every workflow ends in a fallback")
+}
+
+fn evaluate_item(workflows: &Workflows, item: &Item) -> bool {
+    let mut curr = "in";
+
+    loop {
+        let result = evaluate_workflow(&workflows[curr], item);
+        match result {
+            Branch::Accept => { return true; },
+            Branch::Reject => { return false; },
+            Branch::Instruction(a) => { curr = a; },
+        }
+    }
+}
+
+fn get_combinations_recursive(workflows: &Workflows, current: &str, mut curr_limit: AttributeLimit) -> Vec<AttributeLimit> {
+    let workflow = &workflows[current];
+    let mut found_limits = Vec::new();
+
+    for (condition, branch) in workflow {
+        let (passed_limits, rejected_limits) = if let Some(cond) = condition {
+            cond.update(&curr_limit)
+        } else {
+            (curr_limit.clone(), curr_limit)
+        };
+
+        match branch {
+            Branch::Accept => { found_limits.push(passed_limits.clone()) },
+            Branch::Reject => {} ,
+            Branch::Instruction(i) => {found_limits.extend(get_combinations_recursive(workflows, i, passed_limits.clone())) },
+        }
+
+        curr_limit = rejected_limits;
+    }
+
+    found_limits
+}
+
+
+fn get_combinations(workflows: &Workflows) -> Vec<AttributeLimit> {
+    get_combinations_recursive(
+        workflows, 
+        "in", 
+        AttributeLimit {
+            x: Interval::inclusive(1, 4000),
+            m: Interval::inclusive(1, 4000),
+            a: Interval::inclusive(1, 4000),
+            s: Interval::inclusive(1, 4000)
+        }
+    )
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    let (workflows, items) = parse_input(input)?;
+
+    Ok(arith::sum(items.iter()
+        .filter(|i| evaluate_item(&workflows, i))
+        .map(|i| arith::widen(i.value())), step!("sum of ratings"))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let (workflows, _) = parse_input(input)?;
+
+    let combinations = get_combinations(&workflows);
+    let volumes = combinations.iter()
+        .map(|c| arith::product([c.x.len(), c.m.len(), c.a.len(), c.s.len()].map(arith::widen), step!("accepted combinations")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arith::sum(volumes, step!("total accepted combinations"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 19114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 167409079868000);
+    }
+
+    #[test]
+    fn rejects_missing_workflows() {
+        let error = parse("in{x<10:zz,A}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (1, 9, "zz"));
+        let error = parse("px{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn rejects_a_workflow_without_a_fallback() {
+        let error = parse("in{x<10:A,m>5:R}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (1, 11, "m>5:R"));
+    }
+
+    #[test]
+    fn split_less_than() {
+        let limit = Interval::inclusive(1, 4000);
+        assert_eq!(Comparison::LessThan.split(&limit, 2006), (Interval::inclusive(1, 2005), Interval::inclusive(2006, 4000)));
+        assert!(Comparison::LessThan.split(&Interval::inclusive(3000, 4000), 2006).0.is_empty());
+    }
+
+    #[test]
+    fn split_greater_than() {
+        let limit = Interval::inclusive(1, 4000);
+        assert_eq!(Comparison::GreaterThan.split(&limit, 2090), (Interval::inclusive(2091, 4000), Interval::inclusive(1, 2090)));
+        assert!(Comparison::GreaterThan.split(&Interval::inclusive(1, 1000), 2090).0.is_empty());
+    }
+
+    #[test]
+    fn split_partitions() {
+        let limit = Interval::inclusive(1, 4000);
+        for comparison in [Comparison::LessThan, Comparison::GreaterThan] {
+            let (passed, rejected) = comparison.split(&limit, 1351);
+            assert_eq!(passed.len() + rejected.len(), 4000);
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct Rule {
+        attribute: char,
+        comparison: char,
+        value: i64,
+        target: String
+    }
+
+    #[derive(Clone, Debug)]
+    struct Flow {
+        name: String,
+        rules: Vec<Rule>,
+        fallback: String
+    }
+
+    fn render(flows: &[Flow]) -> String {
+        let mut input = String::new();
+        for flow in flows {
+            let rules: Vec<String> = flow.rules.iter()
+                .map(|r| format!("{}{}{}:{}", r.attribute, r.comparison, r.value, r.target))
+                .collect();
+            input += &format!("{}{{{}}}\n", flow.name, [rules, vec![flow.fallback.clone()]].concat().join(","));
+        }
+
+        input + "\n"
+    }
+
+    /// Workflows only send items further down the list, so every item ends up accepted or rejected.
+    fn generate(rng: &mut Rng) -> Vec<Flow> {
+        let count = rng.range(1..5) as usize;
+        let name = |idx: usize| if idx == 0 { "in".to_owned() } else { format!("w{}", idx) };
+
+        (0..count).map(|idx| {
+            let target = |rng: &mut Rng| match rng.range(0..3) {
+                0 if idx + 1 < count => name(rng.range(idx as i64 + 1..count as i64) as usize),
+                1 => "R".to_owned(),
+                _ => "A".to_owned()
+            };
+
+            let rules = (0..rng.range(0..4)).map(|_| Rule {
+                attribute: *rng.choose(&['x', 'm', 'a', 's']),
+                comparison: *rng.choose(&['<', '>']),
+                value: if rng.chance(1, 3) { *rng.choose(&[1, 2, 3999, 4000]) } else { rng.range(1..4001) },
+                target: target(rng)
+            }).collect();
+
+            Flow { name: name(idx), rules, fallback: target(rng) }
+        }).collect()
+    }
+
+    fn shrink(flows: &[Flow]) -> Vec<Vec<Flow>> {
+        let mut smaller = Vec::new();
+
+        for idx in 1..flows.len() {
+            let mut candidate = flows.to_vec();
+            let removed = candidate.remove(idx).name;
+            for flow in candidate.iter_mut() {
+                for target in flow.rules.iter_mut().map(|r| &mut r.target).chain([&mut flow.fallback]) {
+                    if *target == removed {
+                        *target = "R".to_owned();
+                    }
+                }
+            }
+            smaller.push(candidate);
+        }
+
+        for (idx, flow) in flows.iter().enumerate() {
+            for rule_idx in 0..flow.rules.len() {
+                let mut candidate = flows.to_vec();
+                candidate[idx].rules.remove(rule_idx);
+                smaller.push(candidate);
+            }
+        }
+
+        smaller
+    }
+
+    /// Splits each attribute at every threshold and checks one item from each resulting box.
+    fn naive(flows: &[Flow]) -> Int {
+        let (workflows, _) = parse_input(&render(flows)).unwrap();
+
+        let cuts = |attribute: char| {
+            let mut cuts: Vec<i64> = flows.iter()
+                .flat_map(|f| f.rules.iter())
+                .filter(|r| r.attribute == attribute)
+                .map(|r| if r.comparison == '<' { r.value } else { r.value + 1 })
+                .chain([1, 4001])
+                .filter(|c| (1..=4001).contains(c))
+                .collect();
+            cuts.sort();
+            cuts.dedup();
+            cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect::<Vec<(i64, i64)>>()
+        };
+
+        let mut total: Int = 0;
+        for (x, x_size) in cuts('x') {
+            for (m, m_size) in cuts('m') {
+                for (a, a_size) in cuts('a') {
+                    for (s, s_size) in cuts('s') {
+                        if evaluate_item(&workflows, &Item { x, m, a, s }) {
+                            total += arith::widen::<_, Int>(x_size * m_size * a_size * s_size);
+                        }
+                    }
+                }
+            }
+        }
+
+        total
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        common::differential(19, 300, generate, |flows| shrink(flows),
+            |flows| solve_part2(&render(flows)).unwrap(),
+            |flows| naive(flows)
+        );
+    }
+}
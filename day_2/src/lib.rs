@@ -0,0 +1,403 @@
+use std::{cmp::Ordering, collections::{BTreeMap, BTreeSet, BinaryHeap}, fmt};
+use common::{arith, step, Int, Line, ParseError, SolveError};
+
+const BAG: &str = "12 red, 13 green, 14 blue";
+
+/// How many cubes there are of each colour, whether shown in a round or held in a bag. Colours
+/// that aren't listed have none.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cubes(BTreeMap<String, u32>);
+
+impl Cubes {
+    /// Parses counts such as `3 blue, 4 red`.
+    fn parse(line: Line, cubes: &str) -> Result<Self, ParseError> {
+        let mut counts = BTreeMap::new();
+
+        for entry in cubes.split(",") {
+            let (count, colour) = line.split_once(entry.trim(), " ")?;
+            let colour = colour.trim();
+            if colour.is_empty() {
+                return Err(line.error(entry.trim(), "a count and a colour such as '3 blue'"));
+            }
+            if counts.insert(colour.to_owned(), line.number(count.trim())?).is_some() {
+                return Err(line.error(colour, "each colour only once"));
+            }
+        }
+
+        Ok(Cubes(counts))
+    }
+
+    /// Parses a bag given on its own, such as `12 red, 13 green, 14 blue`.
+    pub fn bag(text: &str) -> Result<Self, ParseError> {
+        Cubes::parse(Line { idx: 0, text: text.trim() }, text.trim())
+    }
+
+    pub fn count(&self, colour: &str) -> u32 {
+        self.0.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    fn fits_in(&self, bag: &Cubes) -> bool {
+        self.0.iter().all(|(colour, count)| *count <= bag.count(colour))
+    }
+
+    /// Raises each count to at least the one in `other`.
+    fn include(&mut self, other: &Cubes) {
+        for (colour, count) in &other.0 {
+            let most = self.0.entry(colour.clone()).or_insert(0);
+            *most = (*most).max(*count);
+        }
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (colour, count)) in self.0.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, colour)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The first round of a game that shows more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub game: i32,
+    /// Counted from 1.
+    pub round: usize,
+    pub colour: String,
+    pub shown: u32,
+    pub held: u32
+}
+
+/// What a bag says about the games.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    /// The IDs of the games that could have been played with the bag.
+    pub possible: Vec<i32>,
+    /// Why each of the other games couldn't have been.
+    pub violations: Vec<Violation>,
+    /// The smallest bag every game could have been played with.
+    pub minimal_bag: Cubes,
+    /// For each colour in the bag, the possible games that wouldn't be with one cube fewer of it.
+    pub one_fewer: BTreeMap<String, Vec<i32>>
+}
+
+/// A bag that could have been used for every game, and the natural log of the likelihood of all the
+/// recorded rounds having been drawn from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub bag: Cubes,
+    pub log_likelihood: f64
+}
+
+// Orders estimates from most to least likely, ties going to the smaller bag, so that a max-heap
+// of them keeps the least likely on top.
+struct Ranked(Estimate);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.log_likelihood.total_cmp(&self.0.log_likelihood).then_with(|| self.0.bag.cmp(&other.0.bag))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+struct Game {
+    id: i32,
+    rounds: Vec<Cubes>
+}
+
+impl Game {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let (game, rounds) = line.split_once(line.text, ":")?;
+        let Some(id) = game.trim().strip_prefix("Game ") else {
+            return Err(line.error(game, "'Game <id>'"));
+        };
+        let parsed_rounds = rounds.trim().split(";").map(|r| Cubes::parse(line, r.trim())).collect::<Result<_, _>>()?;
+
+        Ok(Game {
+            id: line.number(id)?,
+            rounds: parsed_rounds
+        })
+    }
+
+    fn possible_with(&self, bag: &Cubes) -> bool {
+        self.rounds.iter().all(
+            |r| r.fits_in(bag)
+        )
+    }
+
+    /// The first round that doesn't fit in `bag`, and the first colour in it by name that doesn't.
+    fn violation(&self, bag: &Cubes) -> Option<Violation> {
+        self.rounds.iter().enumerate().find_map(|(idx, round)| {
+            let (colour, shown) = round.0.iter().find(|(colour, count)| **count > bag.count(colour))?;
+            Some(Violation { game: self.id, round: idx + 1, colour: colour.clone(), shown: *shown, held: bag.count(colour) })
+        })
+    }
+
+    /// The smallest bag every round could have been drawn from.
+    fn minimal_bag(&self) -> Cubes {
+        let mut bag = Cubes::default();
+        for round in &self.rounds {
+            bag.include(round);
+        }
+
+        bag
+    }
+
+    /// The product of the minimal bag's counts of each of `colours`, so a colour the game never
+    /// shows makes it zero.
+    fn get_power<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> Result<Int, SolveError> {
+        let bag = self.minimal_bag();
+
+        Ok(arith::product(colours.into_iter().map(|colour| Int::from(bag.count(colour))), step!("cube power"))?)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut result: Vec<Game> = Vec::new();
+
+    for line in common::lines(input) {
+        result.push(Game::parse(line)?)
+    }
+
+    Ok(result)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+/// The sum of the IDs of the games that could have been played with `bag`.
+pub fn sum_possible_ids(input: &str, bag: &Cubes) -> Result<Int, SolveError> {
+    Ok(arith::sum(parse_input(input)?.iter()
+        .filter(|g| g.possible_with(bag))
+        .map(|g| Int::from(g.id)), step!("sum of game ids"))?)
+}
+
+/// Checks every game against `bag`.
+pub fn query(input: &str, bag: &Cubes) -> Result<Query, ParseError> {
+    let games = parse_input(input)?;
+
+    let mut query = Query { possible: Vec::new(), violations: Vec::new(), minimal_bag: Cubes::default(), one_fewer: BTreeMap::new() };
+    for colour in bag.colours().filter(|colour| bag.count(colour) > 0) {
+        query.one_fewer.insert(colour.to_owned(), Vec::new());
+    }
+
+    for game in &games {
+        let minimal_bag = game.minimal_bag();
+        query.minimal_bag.include(&minimal_bag);
+
+        let Some(violation) = game.violation(bag) else {
+            query.possible.push(game.id);
+            for (colour, games) in query.one_fewer.iter_mut() {
+                if minimal_bag.count(colour) == bag.count(colour) {
+                    games.push(game.id);
+                }
+            }
+            continue;
+        };
+        query.violations.push(violation);
+    }
+
+    Ok(query)
+}
+
+// The log of the chance of drawing `round` from `bag` without replacement, which is the number of
+// ways to pick each colour's cubes out of the bag's over the number of ways to pick that many
+// cubes at all.
+fn log_likelihood(round: &Cubes, bag: &Cubes, ln_factorial: &[f64]) -> f64 {
+    let ln_choose = |n: u32, k: u32| ln_factorial[n as usize] - ln_factorial[k as usize] - ln_factorial[(n - k) as usize];
+
+    let drawn = round.0.values().sum();
+    let held = bag.0.values().sum();
+    let ways: f64 = bag.0.iter().map(|(colour, count)| ln_choose(*count, round.count(colour))).sum();
+
+    ways - ln_choose(held, drawn)
+}
+
+/// Ranks the bags that every game could have been played with by how likely they make the recorded
+/// rounds, treating each round as a draw without replacement with the cubes put back afterwards.
+/// Each colour seen gets from the minimal bag's count up to `limit` cubes, and the `top` most
+/// likely bags are returned, most likely first. Rounds that look like they came from an endless
+/// supply of cubes keep favouring bigger bags, so the likeliest can end up at the limit.
+///
+/// Every candidate is still scored, so the time grows with the product of the colours' ranges,
+/// but only the `top` best are kept.
+pub fn estimate_bag(input: &str, limit: u32, top: usize) -> Result<Vec<Estimate>, ParseError> {
+    let games = parse_input(input)?;
+    let rounds: Vec<&Cubes> = games.iter().flat_map(|g| &g.rounds).collect();
+
+    let mut minimal_bag = Cubes::default();
+    for round in &rounds {
+        minimal_bag.include(round);
+    }
+    if top == 0 || minimal_bag.0.values().any(|count| *count > limit) {
+        return Ok(Vec::new());
+    }
+
+    let most_cubes = limit as usize * minimal_bag.0.len();
+    let ln_factorial: Vec<f64> = (0..=most_cubes)
+        .scan(0.0, |total, n| {
+            *total += (n.max(1) as f64).ln();
+            Some(*total)
+        })
+        .collect();
+
+    // Counts every candidate up from the minimal bag like an odometer, each colour wrapping back
+    // to its minimum after `limit`.
+    let mut best = BinaryHeap::with_capacity(top + 1);
+    let mut bag = minimal_bag.clone();
+    loop {
+        let log_likelihood = rounds.iter().map(|round| log_likelihood(round, &bag, &ln_factorial)).sum();
+        let candidate = Ranked(Estimate { bag: bag.clone(), log_likelihood });
+        if best.len() < top {
+            best.push(candidate);
+        } else if best.peek().is_some_and(|worst| candidate < *worst) {
+            best.pop();
+            best.push(candidate);
+        }
+        let Some(colour) = bag.0.iter().find(|(_, count)| **count < limit).map(|(colour, _)| colour.clone()) else {
+            break;
+        };
+        for (wrapped, count) in bag.0.iter_mut().take_while(|(wrapped, _)| **wrapped != colour) {
+            *count = minimal_bag.count(wrapped);
+        }
+        *bag.0.get_mut(&colour).unwrap() += 1;
+    }
+
+    Ok(best.into_sorted_vec().into_iter().map(|ranked| ranked.0).collect())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    sum_possible_ids(input, &Cubes::bag(BAG).unwrap())
+}
+
+/// The powers are taken over every colour seen anywhere in the input.
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let games = parse_input(input)?;
+    let colours: BTreeSet<&str> = games.iter().flat_map(|g| &g.rounds).flat_map(Cubes::colours).collect();
+
+    let powers = games.iter()
+        .map(|g| g.get_power(colours.iter().copied()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arith::sum(powers, step!("sum of powers"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 2286);
+    }
+
+    const RAINBOW: &str = "\
+Game 1: 3 blue, 2 yellow; 1 red, 4 purple
+Game 2: 5 yellow, 1 blue, 1 red; 2 purple, 1 green
+Game 3: 1 red, 1 green, 1 blue
+";
+
+    #[test]
+    fn any_colours() {
+        let bag = Cubes::bag("3 red, 1 green, 3 blue, 4 yellow, 4 purple").unwrap();
+        assert_eq!(sum_possible_ids(RAINBOW, &bag).unwrap(), 4);
+        assert_eq!(solve_part2(RAINBOW).unwrap(), 10);
+    }
+
+    #[test]
+    fn queries_a_bag() {
+        let query = query(EXAMPLE, &Cubes::bag(BAG).unwrap()).unwrap();
+        assert_eq!(query.possible, [1, 2, 5]);
+        assert_eq!(query.violations, [
+            Violation { game: 3, round: 1, colour: "red".to_owned(), shown: 20, held: 12 },
+            Violation { game: 4, round: 3, colour: "blue".to_owned(), shown: 15, held: 14 }
+        ]);
+        assert_eq!(query.minimal_bag.to_string(), "15 blue, 13 green, 20 red");
+
+        let tight_bag = Cubes::bag("6 blue, 3 green, 6 red, 1 yellow").unwrap();
+        let query = super::query(EXAMPLE, &tight_bag).unwrap();
+        assert_eq!(query.possible, [1, 2, 5]);
+        assert_eq!(query.one_fewer["blue"], [1]);
+        assert_eq!(query.one_fewer["green"], [2, 5]);
+        assert_eq!(query.one_fewer["red"], [5]);
+        assert_eq!(query.one_fewer["yellow"], []);
+    }
+
+    #[test]
+    fn estimates_the_bag() {
+        // Drawing one of each from a bag with one of each is certain, and two of either colour
+        // makes it a two in three chance.
+        let estimates = estimate_bag("Game 1: 1 red, 1 blue", 3, 2).unwrap();
+        assert_eq!(estimates[0].bag.to_string(), "1 blue, 1 red");
+        assert!(estimates[0].log_likelihood.abs() < 1e-9);
+        assert!((estimates[1].log_likelihood - (2.0_f64 / 3.0).ln()).abs() < 1e-9);
+
+        let estimates = estimate_bag(EXAMPLE, 25, 1).unwrap();
+        assert_eq!(estimates.len(), 1);
+        assert!(estimate_bag(EXAMPLE, 19, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn estimates_a_bag_of_many_colours() {
+        // A second cube of any one colour makes drawing one of each a two in five chance, and the
+        // ties go to the smaller bag.
+        const ROUND: &str = "Game 1: 1 red, 1 blue, 1 green, 1 yellow";
+        let estimates = estimate_bag(ROUND, 2, 4).unwrap();
+        let bags: Vec<String> = estimates.iter().map(|estimate| estimate.bag.to_string()).collect();
+        assert_eq!(bags, [
+            "1 blue, 1 green, 1 red, 1 yellow",
+            "1 blue, 1 green, 1 red, 2 yellow",
+            "1 blue, 1 green, 2 red, 1 yellow",
+            "1 blue, 2 green, 1 red, 1 yellow"
+        ]);
+        assert!(estimates[1..].iter().all(|estimate| (estimate.log_likelihood - 0.4_f64.ln()).abs() < 1e-9));
+
+        let estimates = estimate_bag(ROUND, 2, 100).unwrap();
+        assert_eq!(estimates.len(), 16);
+        assert!(estimates.windows(2).all(|pair| pair[0].log_likelihood >= pair[1].log_likelihood));
+        assert_eq!(estimates[15].bag.to_string(), "2 blue, 2 green, 2 red, 2 yellow");
+        assert!(estimate_bag(ROUND, 2, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_a_repeated_colour() {
+        let error = parse("Game 1: 3 blue, 4 red, 2 blue").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 26));
+    }
+}
@@ -1,91 +1,110 @@
-use std::fs::read_to_string;
-use std::collections::HashMap;
+use std::{env, process::ExitCode};
 
-struct Round {
-    red: i32,
-    green: i32,
-    blue: i32
-}
-
-impl Round {
-    fn parse(line: &str) -> Self {
-        let mut values = HashMap::new();
+use common::SolveError;
+use day_2::{Cubes, Estimate, Query};
 
-        line.split(",").map(
-            |c| c.trim().split_once(" ").unwrap()
-        ).for_each(
-            |(count, colour)|  { values.insert(colour.trim(), count.trim().parse::<i32>().unwrap()); }
-        );
+const USAGE: &str = "usage: day_2 [<path>|-] [--bag '<n> <colour>, ...' | --bag-file <path>]
+             [--estimate [--limit <n>] [--top <n>]]";
 
-        Round {
-            red: *values.get("red").unwrap_or(&0),
-            green: *values.get("green").unwrap_or(&0),
-            blue: *values.get("blue").unwrap_or(&0)
-        }
-    }
+enum Mode {
+    Solve,
+    Query(Cubes),
+    Estimate { limit: u32, top: usize }
+}
 
-    fn possible_with(&self, red: i32, green: i32, blue: i32) -> bool {
-        self.red <= red &&
-        self.green <= green &&
-        self.blue <= blue
+fn list(games: &[i32]) -> String {
+    if games.is_empty() {
+        return "none".to_owned();
     }
-}
 
-struct Game {
-    id: i32,
-    rounds: Vec<Round>
+    games.iter().map(i32::to_string).collect::<Vec<_>>().join(", ")
 }
 
-impl Game {
-    fn parse(line: &str) -> Self {
-        let (game, rounds) = line.split_once(":").unwrap();
-        let id = game.trim()[5..].parse::<i32>().unwrap();
-        let parsed_rounds = rounds.trim().split(";").map(|r| Round::parse(r.trim())).collect();
-
-        Game { 
-            id,
-            rounds: parsed_rounds
-        }
-    }
-
-    fn possible_with(&self, red: i32, green: i32, blue: i32) -> bool {
-        self.rounds.iter().all(
-            |r| r.possible_with(red, green, blue)
-        )
+fn report(query: &Query) {
+    println!("possible: {} (sum {})", list(&query.possible), query.possible.iter().sum::<i32>());
+    for violation in &query.violations {
+        println!(
+            "game {}: round {} shows {} {}, bag holds {}",
+            violation.game, violation.round, violation.shown, violation.colour, violation.held
+        );
     }
-
-    fn get_power(&self) -> i32 {
-        let red = self.rounds.iter().map(|r| r.red).max().unwrap();
-        let green = self.rounds.iter().map(|r| r.green).max().unwrap();
-        let blue = self.rounds.iter().map(|r| r.blue).max().unwrap();
-
-        red * green * blue
+    println!("minimal bag: {}", query.minimal_bag);
+    for (colour, games) in &query.one_fewer {
+        println!("one fewer {}: {}", colour, list(games));
     }
 }
 
-fn parse_input() -> Vec<Game> {
-    let mut result: Vec<Game> = Vec::new();
+fn rank(estimates: &[Estimate]) {
+    let Some(best) = estimates.first() else {
+        println!("no bag within the limit fits every game");
+        return;
+    };
 
-    for line in read_to_string("./data.txt").unwrap().lines() {
-        result.push(Game::parse(line))
+    for (idx, estimate) in estimates.iter().enumerate() {
+        println!(
+            "{}. {}: log-likelihood {:.3}, relative likelihood {:.3}",
+            idx + 1, estimate.bag, estimate.log_likelihood, (estimate.log_likelihood - best.log_likelihood).exp()
+        );
     }
-
-    result
 }
 
-fn main() {
-    let input = parse_input();
-
-    let sum: i32 = input.iter()
-        .filter(|g| g.possible_with(12, 13, 14))
-        .map(|g| g.id)
-        .sum();
+fn parse_args() -> Result<(String, Mode), String> {
+    let mut path = None;
+    let mut bag = None;
+    let mut estimate = false;
+    let mut limit = 40;
+    let mut top = 10;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" => {
+                let spec = args.next().ok_or(USAGE)?;
+                bag = Some(Cubes::bag(&spec).map_err(|e| format!("bag: {}", e))?);
+            },
+            "--bag-file" => {
+                let bag_path = args.next().ok_or(USAGE)?;
+                let spec = common::read_input(&bag_path)?;
+                bag = Some(Cubes::bag(&spec).map_err(|e| format!("{}: {}", bag_path, e))?);
+            },
+            "--estimate" => { estimate = true; },
+            "--limit" => {
+                let value = args.next().ok_or(USAGE)?;
+                limit = value.parse().map_err(|_| format!("invalid limit '{}'", value))?;
+            },
+            "--top" => {
+                let value = args.next().ok_or(USAGE)?;
+                top = value.parse().map_err(|_| format!("invalid count '{}'", value))?;
+            },
+            _ if path.is_none() => { path = Some(arg); },
+            _ => return Err(USAGE.to_owned())
+        }
+    }
 
-    println!("{}", sum);
+    let mode = match (bag, estimate) {
+        (Some(_), true) => return Err("--estimate doesn't take a bag".to_owned()),
+        (Some(bag), false) => Mode::Query(bag),
+        (None, true) => Mode::Estimate { limit, top },
+        (None, false) => Mode::Solve
+    };
 
-    let power: i32 = input.iter()
-        .map(|g| g.get_power())
-        .sum();
+    Ok((path.unwrap_or_else(|| "./data.txt".to_owned()), mode))
+}
 
-    println!("{}", power);
+/// Solves the input at the given path, `-` for stdin, or `./data.txt`. Given a bag with `--bag`,
+/// or in a file with `--bag-file`, reports how the games fare against it instead. `--estimate`
+/// ranks the likeliest bags, with at most `--limit` cubes of a colour, showing the `--top` few.
+fn main() -> ExitCode {
+    let loaded = parse_args().and_then(|(path, mode)| Ok((common::read_input(&path)?, mode)));
+    let (input, mode) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => return common::exit(Err(message))
+    };
+
+    let result = match mode {
+        Mode::Solve => common::solve_day(&input, day_2::solve_part1, day_2::solve_part2),
+        Mode::Query(bag) => day_2::query(&input, &bag).map(|query| report(&query)).map_err(SolveError::from),
+        Mode::Estimate { limit, top } => day_2::estimate_bag(&input, limit, top).map(|estimates| rank(&estimates)).map_err(SolveError::from)
+    };
+
+    common::exit(result)
 }
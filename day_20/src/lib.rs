@@ -0,0 +1,278 @@
+use std::collections::{HashMap, VecDeque};
+use common::{arith, step, Cycle, Int, ParseError, SolveError};
+
+fn parse_input(input: &str) -> Result<HashMap<String, Box<dyn Module>>, ParseError>  {
+    let mut modules = HashMap::new();
+
+    for row in common::lines(input) {
+        let (module_str, destination_str) = row.split_once(row.text, "->")?;
+
+        let destinations: Vec<String> = destination_str.split(", ").map(|d| d.trim().to_owned()).collect();
+        let (name, module): (String, Box<dyn Module>) =  match module_str.trim() {
+            "broadcaster" => ("broadcaster".to_owned(), Box::new(Broadcaster { destinations })),
+            module if module.starts_with('%') => (module[1..].to_owned(), Box::new (FlipFlop { is_on: false, destinations} )),
+            module if module.starts_with('&') => (module[1..].to_owned(), Box::new(Conjuction { inputs: HashMap::new(), destinations })),
+            module => { return Err(row.error(module, "'broadcaster', '%<name>' or '&<name>'")); }
+        };
+
+        modules.insert(name, module);
+    }
+
+    for row in input.lines() {
+        let (module_str, destination_str) = row.split_once("->").unwrap();
+        let destinations: Vec<String> = destination_str.split(", ").map(|d| d.trim().to_owned()).collect();
+        let input = module_str.trim().to_string();
+        for dest in &destinations {
+            if let Some(module) = modules.get_mut(dest){
+                module.register_input(&input[1..])
+            }
+        }
+    }
+
+
+    Ok(modules)
+}
+
+#[derive(Debug, Clone)]
+enum Pulse {
+    Low,
+    High
+}
+
+trait Module {
+    fn evaluate(&mut self, input: &str, pulse: Pulse) -> Vec<(String, Pulse)>;
+
+    fn register_input(&mut self, _input: &str) {}
+
+    /// Whatever the module remembers between pulses.
+    fn state(&self) -> Vec<bool> {
+        Vec::new()
+    }
+}
+
+#[derive(Debug)]
+struct Broadcaster {
+    destinations: Vec<String>
+}
+
+impl Module for Broadcaster {
+    fn evaluate(&mut self, _: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        self.destinations.iter().map(|d| (d.clone(), pulse.clone())).collect()
+    }
+}
+
+#[derive(Debug)]
+struct FlipFlop {
+    destinations: Vec<String>,
+    is_on: bool
+}
+
+impl Module for FlipFlop {
+    fn evaluate(&mut self, _: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        if let Pulse::High = pulse {
+            return Vec::new();
+        }
+        
+        self.is_on = !self.is_on;
+
+        let output = if self.is_on {
+            Pulse::High
+        } else {
+            Pulse::Low
+        };
+
+        self.destinations.iter().map(|d| (d.clone(), output.clone())).collect()
+    }
+
+    fn state(&self) -> Vec<bool> {
+        vec![self.is_on]
+    }
+}
+
+#[derive(Debug)]
+struct Conjuction {
+    inputs: HashMap<String, Pulse>,
+    destinations: Vec<String>
+}
+
+impl Module for Conjuction {
+    fn evaluate(&mut self, input: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        *self.inputs.get_mut(input).unwrap() = pulse;
+
+        let all_high = self.inputs.iter().all(
+            |(_,p)| match p {
+                Pulse::High => true,
+                Pulse::Low => false
+            }
+        );
+
+        let output = if all_high {
+            Pulse::Low
+        } else {
+            Pulse::High
+        };
+
+        self.destinations.iter().map(|d| (d.clone(), output.clone())).collect()
+    }
+
+    fn register_input(&mut self, input: &str) {
+        self.inputs.insert(input.to_string(), Pulse::Low);
+    }
+
+    fn state(&self) -> Vec<bool> {
+        let mut inputs: Vec<(&String, &Pulse)> = self.inputs.iter().collect();
+        inputs.sort_by_key(|(name, _)| *name);
+        inputs.iter().map(|(_, pulse)| matches!(pulse, Pulse::High)).collect()
+    }
+}
+
+fn get_state(modules: &HashMap<String, Box<dyn Module>>) -> Vec<bool> {
+    let mut names: Vec<&String> = modules.keys().collect();
+    names.sort();
+    names.iter().flat_map(|name| modules[*name].state()).collect()
+}
+
+fn get_inputs(input: &str, target: &str) -> Vec<String> {
+    input.lines()
+        .map(|row| row.split_once("->").unwrap())
+        .filter(|(_, destination_str)| destination_str.split(", ").any(|d| d.trim() == target))
+        .map(|(module_str, _)| module_str.trim().trim_start_matches(['%', '&']).to_owned())
+        .collect()
+}
+
+fn evaluate_once(watched: &str, modules: &mut HashMap<String, Box<dyn Module>>) -> (i32, i32, Vec<String>) {
+    let mut to_do = VecDeque::from([("button".to_string(), vec![("broadcaster".to_string(), Pulse::Low)])]);
+    let mut high_pulse_count = 0;
+    let mut low_pulse_count = 0;
+    let mut high_sources = Vec::new();
+
+    while let Some((source, pulses)) = to_do.pop_front() {
+        for (module_name, pulse) in pulses {
+            match &pulse {
+                Pulse::High => {
+                    high_pulse_count += 1;
+                    if module_name == watched {
+                        high_sources.push(source.clone());
+                    }
+                },
+                Pulse::Low => { 
+                    low_pulse_count += 1; 
+                }
+            }
+            if let Some(module) = modules.get_mut(&module_name) {
+                let output = module.evaluate(&source, pulse);
+                to_do.push_back((module_name, output));
+            }
+            
+        }
+    }
+
+    (high_pulse_count, low_pulse_count, high_sources)
+}
+
+/// The inputs to rx's feeder are each driven by a 12-bit counter, so they all send it a high pulse
+/// long before this many presses.
+const MAX_PRESSES: Int = 1 << 16;
+
+fn gcd(a: Int, b: Int) -> Int {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+// Each state is the modules after a press along with the pulses that press sent, so once the
+// modules are back where they were every later press is a repeat of one already counted.
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    let mut modules = parse_input(input)?;
+
+    let presses = Cycle::find((get_state(&modules), 0, 0), 1000, |_| {
+        let (high, low, _) = evaluate_once("", &mut modules);
+        (get_state(&modules), high, low)
+    });
+
+    let total_high: Int = presses.sum_to(1000, |(_, high, _)| arith::widen(*high), step!("total high pulses"))?
+        .expect("every press up to the 1000th is recorded");
+    let total_low: Int = presses.sum_to(1000, |(_, _, low)| arith::widen(*low), step!("total low pulses"))?
+        .expect("every press up to the 1000th is recorded");
+
+    Ok(arith::mul(total_high, total_low, step!("product of pulses"))?)
+}
+
+// rx is fed by a single conjunction, so it only receives a low pulse once every input of that
+// conjunction has sent it a high pulse on the same press. Each input is driven by an independent
+// counter, so the answer is the LCM of the first press on which each of them sends a high pulse.
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let mut modules = parse_input(input)?;
+    let feeder = get_inputs(input, "rx").into_iter().next()
+        .ok_or(SolveError::NoSolution(step!("nothing sends pulses to rx")))?;
+    let number_of_inputs = get_inputs(input, &feeder).len();
+    if number_of_inputs == 0 {
+        return Err(SolveError::NoSolution(step!("nothing sends pulses to the module feeding rx")));
+    }
+    let mut first_high: HashMap<String, Int> = HashMap::new();
+    let mut presses = 0;
+    while first_high.len() < number_of_inputs {
+        if presses == MAX_PRESSES {
+            return Err(SolveError::NoSolution(step!("an input of the module feeding rx never sends it a high pulse")));
+        }
+        presses += 1;
+        let (_, _, high_sources) = evaluate_once(&feeder, &mut modules);
+        for source in high_sources {
+            first_high.entry(source).or_insert(presses);
+        }
+    }
+
+    let lcm = first_high.values().try_fold(1, |lcm, presses| arith::mul(lcm / gcd(lcm, *presses), *presses, step!("lcm of presses")))?;
+
+    Ok(lcm) // 232605773145467
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+    const EXAMPLE_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(solve_part1(EXAMPLE_1).unwrap(), 32000000);
+        assert_eq!(solve_part1(EXAMPLE_2).unwrap(), 11687500);
+    }
+
+    #[test]
+    fn part2_needs_rx_and_its_feeder() {
+        assert_eq!(solve_part2(EXAMPLE_1), Err(SolveError::NoSolution(step!("nothing sends pulses to rx"))));
+        assert_eq!(solve_part2(""), Err(SolveError::NoSolution(step!("nothing sends pulses to rx"))));
+        assert_eq!(
+            solve_part2("broadcaster -> a\n%a -> a\n&con -> rx"),
+            Err(SolveError::NoSolution(step!("nothing sends pulses to the module feeding rx")))
+        );
+    }
+
+    #[test]
+    fn part2_gives_up_on_an_input_that_never_fires() {
+        assert_eq!(
+            solve_part2("broadcaster -> a\n%a -> con\n%x -> con\n&con -> rx"),
+            Err(SolveError::NoSolution(step!("an input of the module feeding rx never sends it a high pulse")))
+        );
+        assert_eq!(solve_part2("broadcaster -> a\n%a -> con\n&con -> rx").unwrap(), 1);
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use common::{arith, graph, step, Grid, Int, ParseError, Point, SolveError};
+
+fn parse_input(input: &str) -> Result<(Grid<bool>, Point), ParseError> {
+    let garden = Grid::parse(input, |line, idx, space| {
+        match space {
+            'S' | '#' | '.' => Ok(space),
+            _ => Err(line.char_error(idx, "'.', '#' or 'S'"))
+        }
+    })?;
+
+    let start = garden.iter().find(|(_, space)| **space == 'S').map(|(start, _)| start);
+
+    match start {
+        Some(start) => Ok((garden.map(|space| *space == '#'), start)),
+        None => Err(ParseError::end_of_input(garden.height(), "a start position 'S'"))
+    }
+}
+
+const N: i64 = 4;
+
+fn count_square(row_idx: i64, column_idx: i64, size: i64, distances: &HashMap<Point, i64>) -> i64 {
+    let lower_r = row_idx*size;
+    let upper_r = (row_idx+1)*size;
+    let lower_c = column_idx*size;
+    let upper_c = (column_idx+1)* size;
+
+    let mut odd_count = 0;
+    for r_idx in lower_r..upper_r {
+        for c_idx in lower_c..upper_c {
+            if let Some(dist) = distances.get(&(r_idx as i32, c_idx as i32)) {
+                if *dist <= (N*size + size/2) && *dist % 2 != 0 {
+                    odd_count += 1;
+                }
+            }
+        }
+    }
+
+    odd_count
+}
+
+fn get_distances(start: &Point, rocks: &Grid<bool>) -> HashMap<Point, i64> {
+    let size = rocks.width() as i32;
+    let lower_limit = -N as i32 * size;
+    let upper_limit = (N as i32 + 1) * size;
+    let in_range = |(row, column): &Point| (lower_limit..upper_limit).contains(row) && (lower_limit..upper_limit).contains(column);
+
+    graph::bfs([*start], |point| {
+        common::wrapping_neighbours(*point).filter(|n| in_range(n) && !rocks.get_wrapping(*n))
+    }).into_costs().into_iter().map(|(point, distance)| (point, distance as i64)).collect()
+}
+
+fn count_reachable(input: &str, steps: i64) -> Result<usize, ParseError> {
+    let (rocks, start) = parse_input(input)?;
+
+    let distances = get_distances(&start, &rocks);
+
+    Ok(distances.iter().filter(|(_, d)| **d % 2 == steps % 2 && **d <= steps ).count())
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    count_reachable(input, 64) //3649
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let (rocks, start) = parse_input(input)?;
+
+    let distances = get_distances(&start, &rocks);
+    let size = rocks.width() as i64;
+
+    let full_odd_count = count_square(0, 0, size, &distances);
+    let full_even_count = count_square(1, 0, size, &distances);
+
+    let top_mid = count_square(-4, 0, size, &distances);
+    let bottom_mid = count_square(4, 0, size, &distances);
+    let left_mid = count_square(0, -4, size, &distances);
+    let right_mid = count_square(0, 4, size, &distances);
+
+    let top_left_big = count_square(-3, -1, size, &distances);
+    let top_right_big = count_square(-3, 1, size, &distances);
+    let top_left_small = count_square(-4, -1, size, &distances);
+    let top_right_small = count_square(-4, 1, size, &distances);
+
+    let bottom_left_big = count_square(3, -1, size, &distances);
+    let bottom_right_big = count_square(3, 1, size, &distances);
+    let bottom_left_small = count_square(4, -1, size, &distances);
+    let bottom_right_small = count_square(4, 1, size, &distances);
+
+    let count_even_corners = top_left_small+top_right_small+bottom_left_small+bottom_right_small;
+    let count_odd_corners = top_left_big+top_right_big+bottom_left_big+bottom_right_big;
+    let count_mid = top_mid+bottom_mid+left_mid+right_mid;
+
+    let n: Int = arith::widen((26501365 - size/2) / size);  // 202300
+    let counts = [
+        arith::product([n-1, n-1, arith::widen(full_odd_count)], step!("full odd squares"))?,
+        arith::product([n, n, arith::widen(full_even_count)], step!("full even squares"))?,
+        arith::mul(n-1, arith::widen(count_odd_corners), step!("big corners"))?,
+        arith::mul(n, arith::widen(count_even_corners), step!("small corners"))?,
+        arith::widen(count_mid)
+    ];
+
+    Ok(arith::sum(counts, step!("reachable plots"))?) // 612941134797232
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn reachable_in_example() {
+        assert_eq!(count_reachable(EXAMPLE, 6).unwrap(), 16);
+    }
+}
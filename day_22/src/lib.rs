@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{arith, graph, step, Line, ParseError, SolveError, UInt};
+
+fn parse_input(input: &str) -> Result<HashMap<i32, Brick>, ParseError> {
+    let mut bricks = HashMap::new();
+
+    for row in common::lines(input) {
+        let brick_id = row.idx.try_into().unwrap();
+        let (start_coord, end_coord) = row.split_once(row.text, "~")?;
+        bricks.insert(brick_id, Brick { brick_id,  start: Coord::parse(row, start_coord)?, end: Coord::parse(row, end_coord)? });
+    }
+
+    Ok(bricks)
+}
+
+#[derive(Debug)]
+struct Coord {
+    x: i32,
+    y: i32,
+    z: i32
+}
+
+impl Coord {
+    fn parse(line: Line, coord: &str) -> Result<Self, ParseError> {
+        let coords: Vec<&str> = coord.split(',').collect();
+        if coords.len() != 3 {
+            return Err(line.error(coord, "three coordinates such as '1,0,1'"));
+        }
+
+        Ok(Self {
+            x: line.number(coords[0])?,
+            y: line.number(coords[1])?,
+            z: line.number(coords[2])?
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Brick {
+    brick_id: i32,
+    start: Coord,
+    end: Coord
+}
+
+impl Brick {
+    fn get_height(&self) -> i32 {
+        self.end.z - self.start.z + 1
+    }
+}
+
+fn get_supported_by(bricks: &HashMap<i32, Brick>) -> HashMap<i32, Vec<i32>> {
+    let mut supporting_bricks: HashMap<i32, Vec<i32>> = HashMap::new();
+    let mut current_height: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+
+    let mut sorted_bricks: Vec<&Brick> =  bricks.values().collect();
+    sorted_bricks.sort_by_key(|b| b.start.z);
+
+    for brick in sorted_bricks {
+        let mut cross_section = Vec::new();
+        let mut heights = HashSet::new();
+
+        for x in brick.start.x..=brick.end.x {
+            for y in brick.start.y..=brick.end.y {
+                cross_section.push((x, y));
+                if let Some((curr_top, curr_height)) = current_height.get(&(x,y)) {
+                    heights.insert((*curr_top, *curr_height));
+                }
+            }
+        }
+
+        let max_height = heights.iter().map(|(_,h)| *h).max().unwrap_or(0);
+        let supporting: Vec<i32> = heights.iter()
+            .filter(|(_, h)| *h == max_height)
+            .map(|(b, _h)| *b)
+            .collect();
+
+        supporting_bricks.insert(brick.brick_id, supporting);
+        let new_height = max_height + brick.get_height();
+        cross_section.iter().for_each(|coord| { current_height.insert(*coord, (brick.brick_id, new_height)); });
+    }
+
+    supporting_bricks
+}
+
+fn get_supported(supporting_bricks: &HashMap<i32, Vec<i32>>) -> HashMap<i32, HashSet<i32>> {
+    let mut supported_by: HashMap<i32, HashSet<i32>> = HashMap::new();
+
+    for (brick, supports) in supporting_bricks {
+        for brick_id in supports {
+            if let Some(curr) = supported_by.get_mut(brick_id) {
+                (*curr).insert(*brick);
+            } else {
+                supported_by.insert(*brick_id, HashSet::from([*brick]));
+            }
+        }
+    }
+
+    supported_by
+}
+
+// Going through the bricks above in topological order means that by the time a brick is looked
+// at, every brick under it has already been found to fall or not.
+fn count_falling(disintegrated_brick: i32, supporting: &HashMap<i32, HashSet<i32>>, supported_by: &HashMap<i32, Vec<i32>>) -> Option<usize> {
+    let default = HashSet::new();
+    let above = graph::topological_order([disintegrated_brick], |brick_id| {
+        supporting.get(brick_id).unwrap_or(&default).iter().copied()
+    })?;
+
+    let mut fallen = HashSet::from([disintegrated_brick]);
+    for brick_id in &above[1..] {
+        if supported_by[brick_id].iter().all(|sb| fallen.contains(sb)) {
+            fallen.insert(*brick_id);
+        }
+    }
+
+    Some(fallen.len() - 1)
+}
+
+fn get_unstable_bricks(supported_by: &HashMap<i32, Vec<i32>>) -> HashSet<i32> {
+    supported_by.values()
+        .filter(|s| s.len() == 1)
+        .map(|s| s[0])
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let bricks = parse_input(input)?;
+    let supported_by = get_supported_by(&bricks);
+
+    Ok(bricks.len() - get_unstable_bricks(&supported_by).len()) // 446
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    let bricks = parse_input(input)?;
+    let supported_by = get_supported_by(&bricks);
+    let supporting= get_supported(&supported_by);
+
+    let falling = get_unstable_bricks(&supported_by).iter()
+        .map(|ub| count_falling(*ub, &supporting, &supported_by).map(|count| count as UInt))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(SolveError::NoSolution(step!("the bricks hold each other up in a loop")))?;
+
+    Ok(arith::sum(falling, step!("falling bricks"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 7);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::{hash_map::Entry, HashMap, VecDeque, HashSet};
+use common::{graph, render::{self, Colour}, step, Grid, ParseError, Point, SolveError};
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |line, idx, space| {
+        match space {
+            '#' | '.' | '^' | '>' | 'v' | '<' => Ok(space),
+            _ => Err(line.char_error(idx, "'.', '#' or a slope"))
+        }
+    })
+}
+
+#[derive(Debug)]
+struct Path {
+    location: Point,
+    steps: i32,
+    is_uphill: bool
+}
+
+#[derive(Debug)]
+struct Junction {
+    endpoints: Vec<Path>
+}
+
+fn is_end(coords: &Point, paths: &Grid<char>) -> bool {
+    coords.0 == paths.height() as i32 - 1
+}
+
+fn parse_path(start: Point, paths: &Grid<char>) -> Junction {
+    assert!(paths[start] == '.');
+
+    let mut to_do = VecDeque::from([(start, 0, false)]);
+    let mut endpoints = Vec::new();
+    let mut visited = HashSet::new();
+
+    while let Some((current, curr_steps, is_uphill)) = to_do.pop_front()  {
+        let current_path = paths[current];
+        let (curr_row, curr_col) = current;
+
+        let next_steps: Vec<Point> = paths.neighbours(current)
+            .filter(|n| paths[*n] != '#' && !visited.contains(n))
+            .collect();
+
+        if is_end(&current, paths) || (curr_steps > 0 && next_steps.len() > 1) {
+            assert!(current_path == '.');
+            endpoints.push(Path { location: current, steps: curr_steps, is_uphill });
+            continue;
+        }
+
+        visited.insert(current);
+
+        for next in next_steps {
+            let next_is_uphill = is_uphill || (next.0 < curr_row && current_path == 'v') || (next.1 < curr_col && current_path == '>');
+            to_do.push_back((next, curr_steps + 1, next_is_uphill))
+        }
+    }
+
+    Junction { endpoints }
+}
+
+fn parse_all_paths(paths: &Grid<char>) -> HashMap<Point, Junction> {
+    let mut to_do = vec![(0,1)];
+    let mut junctions = HashMap::new();
+
+    while let Some(next) = to_do.pop() {
+        let junction = parse_path(next, paths);
+        let next_start_points: Vec<Point> = junction.endpoints
+            .iter()
+            .map(|e| e.location.to_owned())
+            .filter(|e| !is_end(e, paths) && !junctions.contains_key(e))
+            .collect();
+        to_do.extend(next_start_points);
+        junctions.insert(next, junction);
+    }
+
+    junctions
+}
+
+/// The length of the longest hike and the junctions it passes through, ending at the exit. Without
+/// going up slopes the junctions can only be visited in one order, so they form a DAG.
+fn get_longest_path(start: Point, end_row: i32, junctions: &HashMap<Point, Junction>, allow_uphill: bool) -> Option<(i32, Vec<Point>)> {
+    let next_junctions = |junction: &Point| -> Vec<(Point, i32)> {
+        junctions.get(junction).map_or(Vec::new(), |j| j.endpoints.iter()
+            .filter(|e| allow_uphill || !e.is_uphill)
+            .map(|e| (e.location, e.steps))
+            .collect())
+    };
+    let is_end = |junction: &Point| junction.0 == end_row;
+
+    if allow_uphill {
+        graph::longest_simple_path(start, next_junctions, is_end)
+    } else {
+        graph::dag_longest_path(start, next_junctions, is_end)
+    }
+}
+
+/// Fills in the tiles between consecutive junctions of a route by walking the corridor that joins them.
+fn get_route_tiles(route: &[Point], paths: &Grid<char>) -> HashSet<Point> {
+    let mut tiles: HashSet<Point> = route.first().copied().into_iter().collect();
+    for pair in route.windows(2) {
+        let mut came_from = HashMap::from([(pair[0], pair[0])]);
+        let mut to_do = VecDeque::from([pair[0]]);
+        while let Some(current) = to_do.pop_front() {
+            if current == pair[1] {
+                break;
+            }
+            // Only the starting junction may branch, so the walk can't wander down other corridors.
+            if current != pair[0] && route.contains(&current) {
+                continue;
+            }
+            for next in paths.neighbours(current).filter(|n| paths[*n] != '#') {
+                if let Entry::Vacant(entry) = came_from.entry(next) {
+                    entry.insert(current);
+                    to_do.push_back(next);
+                }
+            }
+        }
+
+        let mut current = pair[1];
+        while current != pair[0] {
+            tiles.insert(current);
+            current = came_from[&current];
+        }
+    }
+
+    tiles
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+fn longest_hike(input: &str, allow_uphill: bool) -> Result<i32, SolveError> {
+    let paths = parse_input(input)?;
+    let junctions = parse_all_paths(&paths);
+
+    get_longest_path((0,1), paths.height() as i32 - 1, &junctions, allow_uphill)
+        .map(|(length, _)| length)
+        .ok_or(SolveError::NoSolution(step!("there is no hike to the bottom row")))
+}
+
+pub fn solve_part1(input: &str) -> Result<i32, SolveError> {
+    longest_hike(input, false) //2206
+}
+
+pub fn solve_part2(input: &str) -> Result<i32, SolveError> {
+    longest_hike(input, true) //6490
+}
+
+/// The trails with the longest hike down the slopes in one frame and the longest hike over dry
+/// ground in the next, or just the trails for a part without a hike.
+pub fn render(input: &str) -> Result<Vec<Grid<Colour>>, ParseError> {
+    let paths = parse_input(input)?;
+    let junctions = parse_all_paths(&paths);
+    let trails = paths.map(|space| match space {
+        '#' => render::GREEN,
+        '.' => render::BLACK,
+        _ => render::GREY
+    });
+
+    Ok([false, true].iter().map(|allow_uphill| {
+        let route = get_longest_path((0,1), paths.height() as i32 - 1, &junctions, *allow_uphill)
+            .map_or(Vec::new(), |(_, route)| route);
+        let mut frame = trails.clone();
+        for point in get_route_tiles(&route, &paths) {
+            frame[point] = render::RED;
+        }
+        frame
+    }).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 94);
+    }
+
+    #[test]
+    fn route_covers_the_longest_hike() {
+        let paths = parse_input(EXAMPLE).unwrap();
+        let junctions = parse_all_paths(&paths);
+        for allow_uphill in [false, true] {
+            let (length, route) = get_longest_path((0,1), paths.height() as i32 - 1, &junctions, allow_uphill).unwrap();
+            assert_eq!(get_route_tiles(&route, &paths).len() as i32, length + 1);
+        }
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 154);
+    }
+
+    #[test]
+    fn no_hike_to_the_bottom() {
+        const WALLED_OFF: &str = "#.###\n#...#\n#####\n#...#\n###.#\n";
+        let no_solution = Err(SolveError::NoSolution(step!("there is no hike to the bottom row")));
+        assert_eq!(solve_part1(WALLED_OFF), no_solution);
+        assert_eq!(solve_part2(WALLED_OFF), no_solution);
+        assert_eq!(render(WALLED_OFF).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::RangeInclusive;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use common::{arith, step, Int, Line, ParseError, SolveError};
+
+/// The area part 1 looks for crossings in unless it's given another.
+pub const TEST_AREA: RangeInclusive<i64> = 200000000000000..=400000000000000;
+
+#[derive(Debug)]
+struct Hailstone {
+    position: [i64; 3],
+    velocity: [i64; 3]
+}
+
+impl Hailstone {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let (position, velocity) = line.split_once(line.text, "@")?;
+
+        Ok(Hailstone {
+            position: parse_vector(line, position)?,
+            velocity: parse_vector(line, velocity)?
+        })
+    }
+
+    // Where the XY paths of the two hailstones cross, as the times at which each of them gets
+    // there over a shared denominator (made positive), or `None` if the paths are parallel.
+    fn crossing_times(&self, other: &Hailstone) -> Option<(i128, i128, i128)> {
+        let [px_a, py_a, _] = self.position.map(i128::from);
+        let [vx_a, vy_a, _] = self.velocity.map(i128::from);
+        let [px_b, py_b, _] = other.position.map(i128::from);
+        let [vx_b, vy_b, _] = other.velocity.map(i128::from);
+
+        let denominator = vx_a * vy_b - vy_a * vx_b;
+        if denominator == 0 {
+            return None;
+        }
+
+        let (dx, dy) = (px_b - px_a, py_b - py_a);
+        let time_a = dx * vy_b - dy * vx_b;
+        let time_b = dx * vy_a - dy * vx_a;
+
+        Some(if denominator < 0 { (-time_a, -time_b, -denominator) } else { (time_a, time_b, denominator) })
+    }
+
+    /// Whether a rock thrown from `position` at `velocity` hits this hailstone now or later.
+    fn is_hit_by(&self, position: &[BigRational], velocity: &[BigRational]) -> bool {
+        let rational = |value: i64| BigRational::from_integer(BigInt::from(value));
+        let offset: Vec<BigRational> = (0..3).map(|axis| &position[axis] - rational(self.position[axis])).collect();
+        let closing: Vec<BigRational> = (0..3).map(|axis| rational(self.velocity[axis]) - &velocity[axis]).collect();
+
+        // The rock meets the hailstone at offset / closing speed on every axis where they
+        // move at different speeds, and mustn't be apart on any axis where they don't.
+        let Some(axis) = (0..3).find(|axis| !closing[*axis].is_zero()) else {
+            return offset.iter().all(Zero::is_zero);
+        };
+        let time = &offset[axis] / &closing[axis];
+
+        !time.is_negative() && (0..3).all(|axis| offset[axis] == &closing[axis] * &time)
+    }
+
+    fn crosses_inside(&self, other: &Hailstone, area: &RangeInclusive<i64>) -> bool {
+        let Some((time_a, time_b, denominator)) = self.crossing_times(other) else {
+            return false;
+        };
+        if time_a < 0 || time_b < 0 {
+            return false;
+        }
+
+        // Compared with everything scaled up by the denominator so that nothing is rounded.
+        let (min, max) = (i128::from(*area.start()) * denominator, i128::from(*area.end()) * denominator);
+        (0..2).all(|axis| {
+            let scaled = i128::from(self.position[axis]) * denominator + time_a * i128::from(self.velocity[axis]);
+            min <= scaled && scaled <= max
+        })
+    }
+}
+
+fn parse_vector(line: Line, vector: &str) -> Result<[i64; 3], ParseError> {
+    let values: Vec<&str> = vector.split(',').map(str::trim).collect();
+    if values.len() != 3 {
+        return Err(line.error(vector.trim(), "three numbers such as '19, 13, 30'"));
+    }
+
+    Ok([line.number(values[0])?, line.number(values[1])?, line.number(values[2])?])
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    common::lines(input).map(Hailstone::parse).collect()
+}
+
+/// How many pairs of hailstones have XY paths that cross, now or in the future, with both
+/// coordinates of the crossing inside `area`.
+pub fn count_intersections(input: &str, area: RangeInclusive<i64>) -> Result<usize, ParseError> {
+    let hailstones = parse_input(input)?;
+
+    let mut count = 0;
+    for (idx, a) in hailstones.iter().enumerate() {
+        count += hailstones[idx + 1..].iter().filter(|b| a.crosses_inside(b, &area)).count();
+    }
+
+    Ok(count)
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+// A rock at P moving at V hits hailstone i exactly when (P - p_i) x (V - v_i) = 0. The P x V
+// term is the same for every hailstone, so taking the equations for two hailstones away from each
+// other leaves P x (v_i - v_j) + (p_i - p_j) x V = p_i x v_i - p_j x v_j, which is linear in P
+// and V. Two pairs give the six equations needed.
+fn rock_equations(a: &Hailstone, b: &Hailstone) -> [[i128; 7]; 3] {
+    let (pa, va) = (a.position.map(i128::from), a.velocity.map(i128::from));
+    let (pb, vb) = (b.position.map(i128::from), b.velocity.map(i128::from));
+    let d = sub(va, vb);
+    let e = sub(pa, pb);
+    let rhs = sub(cross(pa, va), cross(pb, vb));
+
+    [
+        [0, d[2], -d[1], 0, -e[2], e[1], rhs[0]],
+        [-d[2], 0, d[0], e[2], 0, -e[0], rhs[1]],
+        [d[1], -d[0], 0, -e[1], e[0], 0, rhs[2]]
+    ]
+}
+
+/// Solves a square system of linear equations, each row holding its coefficients followed by its
+/// constant, by Gauss-Jordan elimination. `None` if there isn't a single solution.
+fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let size = rows.len();
+
+    for column in 0..size {
+        let pivot = (column..size).find(|row| !rows[*row][column].is_zero())?;
+        rows.swap(column, pivot);
+
+        let pivot_value = rows[column][column].clone();
+        for value in rows[column].iter_mut() {
+            *value /= pivot_value.clone();
+        }
+
+        let pivot_row = rows[column].clone();
+        for (row_idx, row) in rows.iter_mut().enumerate() {
+            if row_idx != column && !row[column].is_zero() {
+                let factor = row[column].clone();
+                for (value, pivot_value) in row.iter_mut().zip(&pivot_row).skip(column) {
+                    *value -= factor.clone() * pivot_value;
+                }
+            }
+        }
+    }
+
+    Some(rows.into_iter().map(|row| row[size].clone()).collect())
+}
+
+/// The position and velocity of a rock that hits every hailstone, found from the first three
+/// hailstones that pin it down.
+fn find_rock(hailstones: &[Hailstone]) -> Option<Vec<BigRational>> {
+    for a in 0..hailstones.len() {
+        for b in a + 1..hailstones.len() {
+            for c in b + 1..hailstones.len() {
+                let rows = rock_equations(&hailstones[a], &hailstones[b]).into_iter()
+                    .chain(rock_equations(&hailstones[a], &hailstones[c]))
+                    .map(|row| row.iter().map(|value| BigRational::from_integer(BigInt::from(*value))).collect())
+                    .collect();
+
+                if let Some(rock) = solve_linear(rows) {
+                    return Some(rock);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    count_intersections(input, TEST_AREA)
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let hailstones = parse_input(input)?;
+
+    let rock = find_rock(&hailstones).ok_or(SolveError::NoSolution(step!("the hailstones don't pin down a single throw")))?;
+    if !hailstones.iter().all(|hailstone| hailstone.is_hit_by(&rock[..3], &rock[3..])) {
+        return Err(SolveError::NoSolution(step!("no single throw hits every hailstone")));
+    }
+    let total: BigRational = rock[..3].iter().sum();
+    if !total.denom().is_one() {
+        return Err(SolveError::NoSolution(step!("the rock doesn't start at whole coordinates")));
+    }
+    Ok(arith::convert(total.to_integer(), step!("sum of rock coordinates"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(count_intersections(EXAMPLE, 7..=27).unwrap(), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 47);
+    }
+
+    #[test]
+    fn too_few_hailstones_for_a_throw() {
+        let no_solution = Err(SolveError::NoSolution(step!("the hailstones don't pin down a single throw")));
+        assert_eq!(solve_part2(""), no_solution);
+        assert_eq!(solve_part2("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2"), no_solution);
+    }
+
+    #[test]
+    fn a_throw_has_to_hit_every_hailstone() {
+        // The rock from the example starts at 24, 13, 10 and moves at -3, 1, 2.
+        let missed = format!("{}0, 0, 0 @ 1, 1, 1\n", EXAMPLE);
+        assert_eq!(solve_part2(&missed), Err(SolveError::NoSolution(step!("no single throw hits every hailstone"))));
+
+        let hit_earlier = format!("{}27, 12, 8 @ 0, 0, 0\n", EXAMPLE);
+        assert_eq!(solve_part2(&hit_earlier), Err(SolveError::NoSolution(step!("no single throw hits every hailstone"))));
+        let hit_later = format!("{}21, 14, 12 @ 0, 0, 0\n", EXAMPLE);
+        assert_eq!(solve_part2(&hit_later).unwrap(), 47);
+    }
+
+    #[test]
+    fn parallel_paths_never_cross() {
+        let hailstones = parse_input("18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4").unwrap();
+        assert_eq!(hailstones[0].crossing_times(&hailstones[1]), None);
+    }
+
+    #[test]
+    fn rejects_missing_coordinates() {
+        let error = parse("19, 13 @ -2, 1, -2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
@@ -0,0 +1,37 @@
+use std::{env, ops::RangeInclusive, process::ExitCode};
+
+const USAGE: &str = "usage: day_24 [<path>|-] [--min <n>] [--max <n>]";
+fn parse_args() -> Result<(String, RangeInclusive<i64>), String> {
+    let mut path = None;
+    let (mut min, mut max) = (*day_24::TEST_AREA.start(), *day_24::TEST_AREA.end());
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--min" | "--max" => {
+                let value = args.next().ok_or(USAGE)?;
+                let bound = value.parse().map_err(|_| format!("invalid bound '{}'", value))?;
+                if arg == "--min" { min = bound; } else { max = bound; }
+            },
+            _ if path.is_none() => { path = Some(arg); },
+            _ => return Err(USAGE.to_owned())
+        }
+    }
+
+    if min > max {
+        return Err(format!("the test area's minimum {} is above its maximum {}", min, max));
+    }
+
+    Ok((path.unwrap_or_else(|| "./data.txt".to_owned()), min..=max))
+}
+
+/// Solves the input at the given path, `-` for stdin, or `./data.txt`. Part 1 counts crossings
+/// with both coordinates between `--min` and `--max`, which default to the puzzle's test area.
+fn main() -> ExitCode {
+    let loaded = parse_args().and_then(|(path, area)| Ok((common::read_input(&path)?, area)));
+    let (input, area) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => return common::exit(Err(message))
+    };
+
+    common::exit(common::solve_day(&input, |input| day_24::count_intersections(input, area), day_24::solve_part2))
+}
@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use common::{arith, step, ParseError, SolveError, UInt};
+
+// Each wire is stored as a pair of arcs, one each way, numbered so that `arc ^ 1` is the reverse
+// of `arc`. `arcs` holds the component each arc leads to and `outgoing` the arcs leaving each
+// component.
+struct Wiring {
+    names: Vec<String>,
+    arcs: Vec<usize>,
+    outgoing: Vec<Vec<usize>>
+}
+
+fn parse_input(input: &str) -> Result<Wiring, ParseError> {
+    let mut wiring = Wiring { names: Vec::new(), arcs: Vec::new(), outgoing: Vec::new() };
+    let mut ids = HashMap::new();
+
+    for line in common::lines(input) {
+        let (component, connected) = line.split_once(line.text, ":")?;
+        if component.trim().is_empty() {
+            return Err(line.error(component, "a component name"));
+        }
+        if connected.trim().is_empty() {
+            return Err(line.error(connected, "the components it's wired to"));
+        }
+
+        let from = wiring.id(&mut ids, component.trim());
+        for other in connected.split_whitespace() {
+            let to = wiring.id(&mut ids, other);
+            if from != to && !wiring.outgoing[from].iter().any(|arc| wiring.arcs[*arc] == to) {
+                wiring.outgoing[from].push(wiring.arcs.len());
+                wiring.arcs.push(to);
+                wiring.outgoing[to].push(wiring.arcs.len());
+                wiring.arcs.push(from);
+            }
+        }
+    }
+
+    Ok(wiring)
+}
+
+/// The wires whose removal splits the diagram in two, and the components on one side of them.
+struct Cut {
+    wires: Vec<(usize, usize)>,
+    side: HashSet<usize>
+}
+
+impl Wiring {
+    // Components are numbered in the order they first appear so that the search is repeatable.
+    fn id(&mut self, ids: &mut HashMap<String, usize>, name: &str) -> usize {
+        *ids.entry(name.to_owned()).or_insert_with(|| {
+            self.names.push(name.to_owned());
+            self.outgoing.push(Vec::new());
+            self.names.len() - 1
+        })
+    }
+
+    /// Pushes flow from `source` to `sink` with every wire carrying one unit, giving up once
+    /// `limit` units get through. Otherwise the components still reachable from `source` are the
+    /// source side of a minimum cut between the two.
+    fn separate(&self, source: usize, sink: usize, limit: usize) -> Option<HashSet<usize>> {
+        let mut flow = vec![0; self.arcs.len()];
+
+        for _ in 0..limit {
+            // Breadth first search over arcs with room left, remembering the arc each component
+            // was reached by.
+            let mut reached_by = vec![None; self.names.len()];
+            let mut reached = vec![false; self.names.len()];
+            reached[source] = true;
+            let mut to_do = VecDeque::from([source]);
+            while let Some(from) = to_do.pop_front() {
+                for arc in &self.outgoing[from] {
+                    let to = self.arcs[*arc];
+                    if !reached[to] && flow[*arc] < 1 {
+                        reached[to] = true;
+                        reached_by[to] = Some(*arc);
+                        to_do.push_back(to);
+                    }
+                }
+            }
+
+            if !reached[sink] {
+                return Some((0..self.names.len()).filter(|component| reached[*component]).collect());
+            }
+            let mut component = sink;
+            while let Some(arc) = reached_by[component] {
+                flow[arc] += 1;
+                flow[arc ^ 1] -= 1;
+                component = self.arcs[arc ^ 1];
+            }
+        }
+
+        None
+    }
+
+    /// The smallest cut, found as the smallest of the minimum cuts between the first component
+    /// and each of the others, since any cut has to separate the first from at least one of them.
+    /// `None` if there are fewer than two components to separate.
+    fn min_cut(&self) -> Option<Cut> {
+        let mut best: Option<Cut> = None;
+        for sink in 1..self.names.len() {
+            let limit = best.as_ref().map_or(usize::MAX, |cut| cut.wires.len());
+            if let Some(side) = self.separate(0, sink, limit) {
+                let wires = side.iter()
+                    .flat_map(|from| self.outgoing[*from].iter().map(|arc| (*from, self.arcs[*arc])))
+                    .filter(|(_, to)| !side.contains(to))
+                    .collect();
+                best = Some(Cut { wires, side });
+            }
+        }
+
+        best
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+fn nothing_to_cut() -> SolveError {
+    SolveError::NoSolution(step!("there are fewer than two components to separate"))
+}
+
+/// The wires to disconnect, each with its components in alphabetical order.
+pub fn get_cut_wires(input: &str) -> Result<Vec<(String, String)>, SolveError> {
+    let wiring = parse_input(input)?;
+
+    let mut wires: Vec<(String, String)> = wiring.min_cut().ok_or_else(nothing_to_cut)?.wires.iter().map(|(from, to)| {
+        let (from, to) = (wiring.names[*from].clone(), wiring.names[*to].clone());
+        if from < to { (from, to) } else { (to, from) }
+    }).collect();
+    wires.sort();
+
+    Ok(wires)
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    let wiring = parse_input(input)?;
+
+    let side = wiring.min_cut().ok_or_else(nothing_to_cut)?.side.len();
+    let other_side = wiring.names.len() - side;
+
+    Ok(arith::mul(side as UInt, other_side as UInt, step!("product of group sizes"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 54);
+    }
+
+    #[test]
+    fn cuts_the_three_wires() {
+        let expected = [("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")].map(|(a, b)| (a.to_owned(), b.to_owned()));
+        assert_eq!(get_cut_wires(EXAMPLE).unwrap(), expected);
+    }
+
+    #[test]
+    fn nothing_to_cut_without_wires() {
+        assert_eq!(solve_part1(""), Err(nothing_to_cut()));
+        assert_eq!(get_cut_wires(""), Err(nothing_to_cut()));
+    }
+
+    #[test]
+    fn rejects_a_component_without_wires() {
+        let error = parse("jqt: rhn\nxhk:").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
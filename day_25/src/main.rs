@@ -0,0 +1,12 @@
+use common::SolveError;
+
+fn cut_wires(input: &str) -> Result<String, SolveError> {
+    let cuts: Vec<String> = day_25::get_cut_wires(input)?.iter().map(|(from, to)| format!("cut {}/{}", from, to)).collect();
+
+    Ok(cuts.join("\n"))
+}
+
+/// Prints the product of the group sizes and then the wires to cut for them.
+fn main() -> std::process::ExitCode {
+    common::run_day(day_25::parse, day_25::solve_part1, cut_wires)
+}
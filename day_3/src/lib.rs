@@ -0,0 +1,287 @@
+
+use std::{fmt, ops::RangeInclusive};
+use common::{arith, step, Grid, Line, ParseError, Point, SolveError, UInt};
+
+const GEAR_RULE: &str = "* 2 product";
+const SURROUNDING: [Point; 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Blank,
+    /// Part of a number, holding its index in `Schematic::numbers`.
+    Digit(usize),
+    Symbol
+}
+
+struct Number {
+    value: u32,
+    row_idx: usize,
+    start_idx: usize,
+    end_idx: usize
+}
+
+struct Part {
+    value: char,
+    row_idx: usize,
+    column_idx: usize
+}
+
+struct Schematic {
+    cells: Grid<Cell>,
+    numbers: Vec<Number>,
+    parts: Vec<Part>
+}
+
+fn parse_input(input: &str) -> Result<Schematic, ParseError> {
+    let characters = Grid::parse(input, |line, idx, character| match character {
+        x if x.is_whitespace() => Err(line.char_error(idx, "a digit, '.' or a symbol")),
+        x => Ok(x)
+    })?;
+
+    let mut numbers: Vec<Number> = Vec::new();
+    for row_idx in 0..characters.height() {
+        let row = characters.row(row_idx);
+        let mut column_idx = 0;
+
+        while column_idx < row.len() {
+            let number_len = row[column_idx..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if number_len > 0 {
+                numbers.push(Number {
+                    value: row[column_idx..column_idx + number_len].iter().fold(0, |value, c| value * 10 + c.to_digit(10).unwrap()),
+                    row_idx,
+                    start_idx: column_idx,
+                    end_idx: column_idx + number_len - 1
+                });
+            }
+            column_idx += number_len.max(1);
+        }
+    }
+
+    let mut cells = characters.map(|character| match character {
+        '.' | '0'..='9' => Cell::Blank,
+        _ => Cell::Symbol
+    });
+    for (idx, number) in numbers.iter().enumerate() {
+        for column_idx in number.start_idx..=number.end_idx {
+            cells[(number.row_idx as i32, column_idx as i32)] = Cell::Digit(idx);
+        }
+    }
+
+    let parts = characters.iter()
+        .filter(|(point, _)| matches!(cells[*point], Cell::Symbol))
+        .map(|((row, column), value)| Part { value: *value, row_idx: row as usize, column_idx: column as usize })
+        .collect();
+
+    Ok(Schematic {
+        cells, numbers, parts
+    })
+}
+
+impl Schematic {
+    /// The cells around `point`, diagonals included, that are on the schematic.
+    fn surrounding(&self, (row, column): Point) -> impl Iterator<Item = Cell> + '_ {
+        SURROUNDING.iter().filter_map(move |(d_row, d_column)| self.cells.get((row + d_row, column + d_column)).copied())
+    }
+}
+
+impl Number {
+    fn has_adjacent_part(&self, schematic: &Schematic) -> bool {
+        (self.start_idx..=self.end_idx).any(
+            |column_idx| schematic.surrounding((self.row_idx as i32, column_idx as i32)).any(|c| matches!(c, Cell::Symbol))
+        )
+    }
+}
+
+impl Part {
+    /// Each number touching the part once, in the order they appear in the schematic.
+    fn get_adjacent_numbers<'a>(&self, schematic: &'a Schematic) -> Vec<&'a Number> {
+        let mut adjacent: Vec<usize> = schematic.surrounding((self.row_idx as i32, self.column_idx as i32))
+            .filter_map(|c| match c {
+                Cell::Digit(idx) => Some(idx),
+                _ => None
+            })
+            .collect();
+        adjacent.sort();
+        adjacent.dedup();
+
+        adjacent.into_iter().map(|idx| &schematic.numbers[idx]).collect()
+    }
+}
+
+/// How the numbers next to a gear make its ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+    Max
+}
+
+impl Combine {
+    fn parse(line: Line, combine: &str) -> Result<Self, ParseError> {
+        match combine {
+            "product" => Ok(Self::Product),
+            "sum" => Ok(Self::Sum),
+            "max" => Ok(Self::Max),
+            _ => Err(line.error(combine, "'product', 'sum' or 'max'"))
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Product => "product",
+            Self::Sum => "sum",
+            Self::Max => "max"
+        }
+    }
+
+    fn apply(&self, values: impl Iterator<Item = UInt>) -> Result<UInt, SolveError> {
+        match self {
+            Self::Product => Ok(arith::product(values, step!("gear ratio"))?),
+            Self::Sum => Ok(arith::sum(values, step!("gear ratio"))?),
+            Self::Max => Ok(values.max().unwrap_or(0))
+        }
+    }
+}
+
+/// Which parts are gears and what each is worth, written like `* 2 product`: the symbols a gear
+/// can be, how many numbers it has to touch (a count, or a range like `2-4`) and how they combine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    symbols: Vec<char>,
+    neighbours: RangeInclusive<usize>,
+    combine: Combine
+}
+
+impl GearRule {
+    fn parse_line(line: Line) -> Result<Self, ParseError> {
+        let fields: Vec<&str> = line.text.split_whitespace().collect();
+        let [symbols, neighbours, combine] = fields[..] else {
+            return Err(line.error(line.text, "a rule such as '* 2 product'"));
+        };
+
+        if let Some(bad) = symbols.chars().find(|c| c.is_ascii_digit() || *c == '.') {
+            return Err(line.error(symbols, &format!("symbols other than '{}'", bad)));
+        }
+        let neighbours = match neighbours.split_once("-") {
+            Some((min, max)) => line.number(min)?..=line.number(max)?,
+            None => line.number(neighbours)?..=line.number(neighbours)?
+        };
+        if neighbours.is_empty() {
+            return Err(line.error(fields[1], "a neighbour range from the smaller count to the larger"));
+        }
+        Ok(GearRule { symbols: symbols.chars().collect(), neighbours, combine: Combine::parse(line, combine)? })
+    }
+
+    pub fn parse(rule: &str) -> Result<Self, ParseError> {
+        GearRule::parse_line(Line { idx: 0, text: rule.trim() })
+    }
+
+    /// One rule per line, skipping blank lines.
+    pub fn parse_all(rules: &str) -> Result<Vec<Self>, ParseError> {
+        common::lines(rules).filter(|line| !line.text.trim().is_empty()).map(GearRule::parse_line).collect()
+    }
+}
+
+impl fmt::Display for GearRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbols: String = self.symbols.iter().collect();
+        if self.neighbours.start() == self.neighbours.end() {
+            write!(f, "{} {} {}", symbols, self.neighbours.start(), self.combine.name())
+        } else {
+            write!(f, "{} {}-{} {}", symbols, self.neighbours.start(), self.neighbours.end(), self.combine.name())
+        }
+    }
+}
+
+/// The sum of the ratios of every gear by `rule`.
+pub fn sum_gear_ratios(input: &str, rule: &GearRule) -> Result<UInt, SolveError> {
+    let input = parse_input(input)?;
+    let potential_gears = input.parts.iter().filter(|p| rule.symbols.contains(&p.value));
+
+    let ratios = potential_gears.filter_map(
+        |g| {
+            let adjacent = g.get_adjacent_numbers(&input);
+            rule.neighbours.contains(&adjacent.len())
+                .then(|| rule.combine.apply(adjacent.iter().map(|n| UInt::from(n.value))))
+        }
+    ).collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arith::sum(ratios, step!("sum of gear ratios"))?)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    let input = parse_input(input)?;
+
+    Ok(arith::sum(input.numbers.iter().filter(|n| n.has_adjacent_part(&input)).map(|n| UInt::from(n.value)), step!("sum of part numbers"))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    sum_gear_ratios(input, &GearRule::parse(GEAR_RULE).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 4361);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 467835);
+    }
+
+    #[test]
+    fn symbols_on_the_edges() {
+        let input = "*12\n3..\n..4\n.5#\n";
+        assert_eq!(solve_part1(input).unwrap(), 12 + 3 + 4 + 5);
+        assert_eq!(solve_part2(input).unwrap(), 12 * 3);
+    }
+
+    #[test]
+    fn other_gear_rules() {
+        let rules = GearRule::parse_all("* 1 max\n\n*#+$ 1-2 sum\n* 0-9 product\n").unwrap();
+        assert_eq!(rules.iter().map(GearRule::to_string).collect::<Vec<_>>(), ["* 1 max", "*#+$ 1-2 sum", "* 0-9 product"]);
+
+        let totals: Vec<UInt> = rules.iter().map(|rule| sum_gear_ratios(EXAMPLE, rule).unwrap()).collect();
+        assert_eq!(totals, [617, 467 + 35 + 633 + 617 + 592 + 664 + 755 + 598, 467 * 35 + 617 + 755 * 598]);
+    }
+
+    #[test]
+    fn rejects_bad_rules() {
+        let error = GearRule::parse("* 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = GearRule::parse("*. 2 sum").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = GearRule::parse("* 2 mean").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        let error = GearRule::parse("* 3-1 sum").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert!(GearRule::parse("* 2-2 sum").is_ok());
+    }
+
+    #[test]
+    fn numbers_touching_twice_count_once() {
+        let schematic = parse_input("123\n.*.\n").unwrap();
+        assert_eq!(schematic.parts[0].get_adjacent_numbers(&schematic).len(), 1);
+    }
+}
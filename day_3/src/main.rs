@@ -1,122 +1,54 @@
+use std::{env, process::ExitCode};
 
-use std::fs::read_to_string;
+use common::SolveError;
+use day_3::GearRule;
 
-struct Number {
-    value: u32,
-    row_idx: usize,
-    start_idx: usize,
-    end_idx: usize
-}
-
-struct Part {
-    value: char,
-    row_idx: usize,
-    column_idx: usize
-}
-
-struct Schematic {
-    numbers: Vec<Number>,
-    parts: Vec<Part>
-}
-
-fn parse_input() -> Schematic {
-    let mut numbers: Vec<Number> = Vec::new();
-    let mut parts: Vec<Part> = Vec::new();
-
-    for (row_idx, line) in read_to_string("./data.txt").unwrap().lines().enumerate() {
-        let mut number_part = 0;
-        let mut number_len = 0;
-        let mut last_number_idx = 0;
-        let line_length = line.len();
+const USAGE: &str = "usage: day_3 [<path>|-] [--rule '<symbols> <count>[-<max>] product|sum|max']... [--rules <path>]";
 
-        for (column_idx, character) in line.chars().enumerate() {
-            let mut number_ready = false;
-
-            match character.to_digit(10) {
-                Some(x) => {
-                    number_part = number_part * 10 + x;
-                    number_len += 1;
-                    last_number_idx = column_idx;
-                    if column_idx == line_length - 1 {
-                        number_ready = true
-                    }
-                },
-                None => { number_ready = number_len > 0; }
-            }
-            if number_ready {
-                numbers.push(Number{
-                    value: number_part,
-                    row_idx,
-                    start_idx: last_number_idx + 1 - number_len,
-                    end_idx: last_number_idx
-                });
-
-                number_part = 0;
-                number_len = 0;
-            }
-
-            match character {
-                '.' => {},
-                '0'..='9' => {},
-                x => parts.push(Part{
-                    value: x, row_idx, column_idx
-                })
-            }
-        }
+fn apply_rules(input: &str, rules: &[GearRule]) -> Result<(), SolveError> {
+    for rule in rules {
+        println!("{}: {}", rule, day_3::sum_gear_ratios(input, rule)?);
     }
 
-    Schematic {
-        numbers, parts
-    }
+    Ok(())
 }
 
-impl Number {
-    fn is_adjacent(&self, part: &Part) -> bool {
-        let is_adjacent_row = self.row_idx >= part.row_idx - 1 && self.row_idx <= part.row_idx + 1;
-        let is_adjacent_column = self.start_idx <= part.column_idx + 1 && self.end_idx >= part.column_idx - 1;
-
-        is_adjacent_row && is_adjacent_column
+fn parse_args() -> Result<(String, Vec<GearRule>), String> {
+    let mut path = None;
+    let mut rules = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rule" => {
+                let rule = args.next().ok_or(USAGE)?;
+                rules.push(GearRule::parse(&rule).map_err(|e| format!("rule: {}", e))?);
+            },
+            "--rules" => {
+                let rules_path = args.next().ok_or(USAGE)?;
+                let text = common::read_input(&rules_path)?;
+                rules.extend(GearRule::parse_all(&text).map_err(|e| format!("{}: {}", rules_path, e))?);
+            },
+            _ if path.is_none() => { path = Some(arg); },
+            _ => return Err(USAGE.to_owned())
+        }
     }
 
-    fn has_adjacent_part(&self, parts: &Vec<Part>) -> bool {
-        parts.iter().any(|p| self.is_adjacent(p))
-    } 
-    
+    Ok((path.unwrap_or_else(|| "./data.txt".to_owned()), rules))
 }
 
-impl Part {
-    fn get_adjacent_numbers<'a>(&'a self, numbers: &'a Vec<Number>) -> Vec<&Number> {
-        numbers.iter().filter(|n| n.is_adjacent(self)).collect()
+/// Solves the input at the given path, `-` for stdin, or `./data.txt`. Given gear rules with
+/// `--rule` or in a file with `--rules`, prints the total of the gear ratios by each instead.
+fn main() -> ExitCode {
+    let loaded = parse_args().and_then(|(path, rules)| Ok((common::read_input(&path)?, rules)));
+    let (input, rules) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => return common::exit(Err(message))
+    };
+
+    if rules.is_empty() {
+        common::exit(common::solve_day(&input, day_3::solve_part1, day_3::solve_part2))
+    } else {
+        common::exit(apply_rules(&input, &rules))
     }
 }
-
-fn main() {
-    let input = parse_input();
-
-    let total:u32 = input.numbers.iter().filter(|n| n.has_adjacent_part(&input.parts)).map(|n| n.value).sum();
-
-    println!("{}", total);
-
-    let potential_gears: Vec<&Part> = input.parts.iter().filter(|p| p.value == '*').collect();
-
-    let total2: u32 = potential_gears.iter().map(
-        |g| {
-            let adjacent = g.get_adjacent_numbers(&input.numbers);
-
-            match adjacent[..] {
-                [a,b] => a.value * b.value,
-                _ => 0
-            }
-        }
-    ).sum();
-
-    println!("{}", total2);
-
-    // let number  = 220;
-    // let part =2;
-    // println!("{} {}", input.parts[part].row_idx, input.parts[part].column_idx);
-    // println!("{} {} {}", input.numbers[number].value, input.numbers[number].start_idx, input.numbers[number].end_idx);
-    // println!("{}", input.numbers[number].has_adjacent_part(&input.parts));
-
-    // println!("{}", input.numbers.iter().enumerate().filter(|x| x.1.value == 455).map(|x| x.0).collect::<Vec<_>>()[1])
-}
@@ -0,0 +1,100 @@
+
+use std::collections::HashMap;
+use common::{arith, step, Line, ParseError, SolveError, UInt};
+
+struct Game {
+    id: u32,
+    actual: Vec<i32>,
+    guessed: Vec<i32>
+}
+
+fn parse_numbers(line: Line, numbers_str: &str) -> Result<Vec<i32>, ParseError> {
+    let mut numbers: Vec<i32> =  Vec::new();
+
+    for n in numbers_str.split_whitespace() {
+        numbers.push(line.number(n)?)
+    }
+
+    Ok(numbers)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::new();
+
+    for line in common::lines(input) {
+        let (_, game) = line.split_once(line.text, ":")?;
+        let (actual, guessed) = line.split_once(game, "|")?;
+
+        games.push(Game {
+            id: (line.idx + 1).try_into().unwrap(),
+            actual: parse_numbers(line, actual)?,
+            guessed: parse_numbers(line, guessed)?
+        })
+    }
+
+    Ok(games)
+}
+
+impl Game {
+    fn get_num_correct_guesses(&self) -> u32 {
+        self.guessed.iter().filter(|n| self.actual.contains(n)).collect::<Vec<_>>().len().try_into().unwrap()
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<UInt, SolveError> {
+    let points = parse_input(input)?.iter().map(
+        |g| {
+            let correct: u32 = g.get_num_correct_guesses();
+            if correct > 0 {
+                arith::pow(2, correct - 1, step!("card points"))
+            } else { Ok(0) }
+        }
+    ).collect::<Result<Vec<UInt>, _>>()?;
+
+    Ok(arith::sum(points, step!("total points"))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<UInt, SolveError> {
+    let input = parse_input(input)?;
+    let mut copies: HashMap<u32, UInt> = HashMap::new();
+    input.iter().for_each(|g| { copies.insert(g.id, 1); });
+
+    for g in &input {
+        let number_of_copies = *copies.get(&g.id).unwrap();
+        let correct = g.get_num_correct_guesses();
+        for idx in 1..=correct {
+            let count = copies.get_mut(&(g.id + idx)).unwrap();
+            *count = arith::add(*count, number_of_copies, step!("scratchcard copies"))?;
+        }
+    }
+
+    Ok(arith::sum(copies.values().copied(), step!("total scratchcards"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 30);
+    }
+}
@@ -0,0 +1,343 @@
+use common::{step, Interval, IntervalSet, Line, Lines, ParseError, SolveError};
+
+struct MapEntry {
+    source: Interval,
+    offset: i64
+}
+
+struct Map {
+    entries: Vec<MapEntry>
+}
+
+struct Mappings {
+    seed_to_soil: Map,
+    soil_to_fertilizer: Map,
+    fertilizer_to_water: Map,
+    water_to_light: Map,
+    light_to_temperature: Map,
+    temperature_to_humidity: Map,
+    humidity_to_location: Map,
+}
+
+fn parse_mappings(lines: &mut Lines, header: &str) -> Result<Map, ParseError> {
+    let mut entries = Vec::new();
+
+    lines.expect(&format!("'{}'", header))?.expect(header)?;
+
+    for line in lines {
+        match line.text {
+            "" => break,
+            _ => {
+                entries.push(MapEntry::parse(line)?);
+            }
+
+        }
+    }
+
+    Ok(Map { entries })
+}
+
+fn parse_seed_line(line: Line) -> Result<Vec<i64>, ParseError> {
+    let mut seeds: Vec<i64> = Vec::new();
+
+    let Some(seeds_str) = line.text.strip_prefix("seeds:") else {
+        return Err(line.error(line.text, "'seeds:'"));
+    };
+
+    for seed in seeds_str.split_whitespace() {
+        seeds.push(line.number(seed)?);
+    }
+
+    Ok(seeds)
+}
+
+fn parse_seeds_1(parsed: &[i64]) -> IntervalSet {
+    parsed.iter().map(|id| Interval::inclusive(*id, *id)).collect()
+}
+
+/// The second part reads the seeds as pairs of a start and a length.
+fn parse_seeds_2(line: Line, input: &[i64]) -> Result<IntervalSet, ParseError> {
+    if input.len() % 2 == 1 {
+        let unpaired = line.text.split_whitespace().last().unwrap_or(line.text);
+        return Err(line.error(unpaired, "seeds in pairs of a start and a length"));
+    }
+
+    Ok(input.chunks_exact(2).map(|pair| Interval::new(pair[0], pair[0] + pair[1])).collect())
+}
+
+fn parse_input(input: &str) -> Result<(Line<'_>, Vec<i64>, Mappings), ParseError> {
+    let mut lines =  common::lines(input);
+
+    let seed_line = lines.expect("'seeds:'")?;
+    let seeds = parse_seed_line(seed_line)?;
+    lines.expect("an empty line")?.expect("")?;
+
+    let seed_to_soil = parse_mappings(&mut lines, "seed-to-soil map:")?;
+    let soil_to_fertilizer = parse_mappings(&mut lines, "soil-to-fertilizer map:")?;
+    let fertilizer_to_water = parse_mappings(&mut lines, "fertilizer-to-water map:")?;
+    let water_to_light = parse_mappings(&mut lines, "water-to-light map:")?;
+    let light_to_temperature = parse_mappings(&mut lines, "light-to-temperature map:")?;
+    let temperature_to_humidity = parse_mappings(&mut lines, "temperature-to-humidity map:")?;
+    let humidity_to_location = parse_mappings(&mut lines, "humidity-to-location map:")?;
+
+    Ok((seed_line, seeds, Mappings {
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location
+    }))
+}
+
+impl MapEntry {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let destination_start: i64;
+        let source_start: i64;
+        let length: i64;
+        match line.text.split_whitespace().collect::<Vec<_>>()[..] {
+            [a, b, c] => {
+                destination_start = line.number(a)?;
+                source_start = line.number(b)?;
+                length = line.number(c)?;
+            },
+            _ => return Err(line.error(line.text, "three numbers"))
+        }
+
+        Ok(MapEntry {
+            source: Interval::new(source_start, source_start + length), offset: destination_start - source_start
+        })
+    }
+}
+
+impl Map {
+    // Every id covered by an entry is moved by that entry's offset and anything left over keeps its id.
+    fn map(&self, ids: &IntervalSet) -> IntervalSet {
+        let mut mapped = IntervalSet::new();
+        let mut unmapped = ids.clone();
+
+        for entry in &self.entries {
+            let source = IntervalSet::from(entry.source);
+            mapped = mapped.union(&ids.intersection(&source).shift(entry.offset));
+            unmapped = unmapped.difference(&source);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+impl Mappings {
+    fn get_closest_location(&self, seeds: &IntervalSet) -> Result<i64, SolveError> {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location
+        ].iter()
+            .fold(seeds.clone(), |ids, map| map.map(&ids))
+            .first()
+            .ok_or(SolveError::NoSolution(step!("there are no seeds to plant")))
+    }
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<i64, SolveError> {
+    let (_, seeds, mappings) = parse_input(input)?;
+
+    mappings.get_closest_location(&parse_seeds_1(&seeds)) // 309796150
+}
+
+pub fn solve_part2(input: &str) -> Result<i64, SolveError> {
+    let (seed_line, seeds, mappings) = parse_input(input)?;
+
+    mappings.get_closest_location(&parse_seeds_2(seed_line, &seeds)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 35);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 46);
+    }
+
+    #[test]
+    fn rejects_an_unpaired_seed() {
+        let unpaired = EXAMPLE.replacen("13", "13 7", 1);
+        assert_eq!(solve_part1(&unpaired).unwrap(), 29);
+        let Err(SolveError::Parse(error)) = solve_part2(&unpaired) else {
+            panic!("an unpaired seed should fail to parse for part 2");
+        };
+        assert_eq!((error.line, error.column), (1, 20));
+    }
+
+    #[test]
+    fn no_seeds_have_no_location() {
+        let no_seeds = EXAMPLE.replacen("79 14 55 13", "", 1);
+        assert_eq!(solve_part1(&no_seeds), Err(SolveError::NoSolution(step!("there are no seeds to plant"))));
+        assert_eq!(solve_part2(&no_seeds), Err(SolveError::NoSolution(step!("there are no seeds to plant"))));
+    }
+
+    #[test]
+    fn map_moves_covered_ids_and_keeps_the_rest() {
+        let map = Map { entries: vec![
+            MapEntry { source: Interval::new(10, 15), offset: 100 },
+            MapEntry { source: Interval::new(20, 25), offset: -20 }
+        ] };
+        let ids: IntervalSet = [Interval::new(5, 22)].into_iter().collect();
+        let expected: IntervalSet = [Interval::new(0, 2), Interval::new(5, 10), Interval::new(15, 20), Interval::new(110, 115)].into_iter().collect();
+
+        assert_eq!(map.map(&ids), expected);
+    }
+
+    const HEADERS: [&str; 7] = [
+        "seed-to-soil map:", "soil-to-fertilizer map:", "fertilizer-to-water map:", "water-to-light map:",
+        "light-to-temperature map:", "temperature-to-humidity map:", "humidity-to-location map:"
+    ];
+
+    // Seed ranges as (start, length) and map entries as (destination, source, length).
+    #[derive(Clone, Debug)]
+    struct Almanac {
+        seeds: Vec<(i64, i64)>,
+        maps: Vec<Vec<(i64, i64, i64)>>
+    }
+
+    impl Almanac {
+        fn render(&self) -> String {
+            let seeds: Vec<String> = self.seeds.iter().map(|(start, length)| format!("{} {}", start, length)).collect();
+            let mut text = format!("seeds: {}\n", seeds.join(" "));
+
+            for (header, entries) in HEADERS.iter().zip(&self.maps) {
+                text += &format!("\n{}\n", header);
+                for (destination, source, length) in entries {
+                    text += &format!("{} {} {}\n", destination, source, length);
+                }
+            }
+
+            text
+        }
+    }
+
+    fn generate(rng: &mut Rng) -> Almanac {
+        let seeds = (0..rng.range(1..4)).map(|_| (rng.range(0..60), rng.range(1..15))).collect();
+        let maps = (0..7).map(|_| {
+            let mut entries = Vec::new();
+            let mut source = rng.range(0..20);
+            while source < 100 && entries.len() < 4 {
+                let length = rng.range(1..20);
+                entries.push((rng.range(0..100), source, length));
+                source += length + rng.range(0..10);
+            }
+            entries
+        }).collect();
+
+        Almanac { seeds, maps }
+    }
+
+    fn shrink(almanac: &Almanac) -> Vec<Almanac> {
+        let mut smaller = Vec::new();
+
+        for idx in 0..almanac.seeds.len() {
+            let mut candidate = almanac.clone();
+            if almanac.seeds.len() > 1 {
+                candidate.seeds.remove(idx);
+                smaller.push(candidate.clone());
+                candidate = almanac.clone();
+            }
+            if almanac.seeds[idx].1 > 1 {
+                candidate.seeds[idx].1 -= 1;
+                smaller.push(candidate);
+            }
+        }
+
+        for (map_idx, entries) in almanac.maps.iter().enumerate() {
+            for (entry_idx, entry) in entries.iter().enumerate() {
+                let mut candidate = almanac.clone();
+                candidate.maps[map_idx].remove(entry_idx);
+                smaller.push(candidate);
+
+                if entry.2 > 1 {
+                    let mut candidate = almanac.clone();
+                    candidate.maps[map_idx][entry_idx].2 -= 1;
+                    smaller.push(candidate);
+                }
+            }
+        }
+
+        smaller
+    }
+
+    fn naive(almanac: &Almanac) -> (i64, i64) {
+        let locate = |seed: i64| almanac.maps.iter().fold(seed, |id, entries| {
+            entries.iter()
+                .find(|(_, source, length)| *source <= id && id < source + length)
+                .map_or(id, |(destination, source, _)| destination + id - source)
+        });
+
+        let part1 = almanac.seeds.iter().flat_map(|(start, length)| [*start, *length]).map(locate).min().unwrap();
+        let part2 = almanac.seeds.iter().flat_map(|(start, length)| *start..start + length).map(locate).min().unwrap();
+
+        (part1, part2)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        common::differential(5, 300, generate, shrink,
+            |almanac| {
+                let input = almanac.render();
+                (solve_part1(&input).unwrap(), solve_part2(&input).unwrap())
+            },
+            naive
+        );
+    }
+}
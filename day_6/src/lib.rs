@@ -0,0 +1,89 @@
+use common::{arith, step, Int, Line, ParseError, SolveError};
+
+struct Race {
+    time: i64,
+    record: i64
+}
+
+fn parse_numbers(line: Line) -> Result<Vec<i64>, ParseError> {
+    let (_, numbers) = line.split_once(line.text, ":")?;
+
+    numbers.split_whitespace().map(|n| line.number(n)).collect()
+}
+
+fn parse_joined_number(line: Line) -> Result<i64, ParseError> {
+    let (_, numbers) = line.split_once(line.text, ":")?;
+
+    numbers.split_whitespace().collect::<String>().parse().map_err(|_| line.error(numbers, "a number"))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = common::lines(input);
+    let times = parse_numbers(lines.expect("'Time:'")?)?;
+    let records = parse_numbers(lines.expect("'Distance:'")?)?;
+
+    Ok(times.iter().zip(records).map(|(time, record)| Race { time: *time, record }).collect())
+}
+
+fn parse_input_joined(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = common::lines(input);
+    let time = parse_joined_number(lines.expect("'Time:'")?)?;
+    let record = parse_joined_number(lines.expect("'Distance:'")?)?;
+
+    Ok(Vec::from([Race { time, record }]))
+}
+
+impl Race {
+    fn get_wins(&self) -> Vec<i64> {
+        let mut wins = Vec::new();
+        for charge_time in 0..=self.time {
+            let distance = charge_time * (self.time - charge_time);
+            if distance > self.record {
+                wins.push(charge_time);
+            }
+        }
+
+        wins
+    }
+}
+
+fn get_all_wins(races: Vec<Race>) -> Result<Vec<Int>, SolveError> {
+    Ok(races.iter()
+    .map(|r| r.get_wins())
+    .map(|w| arith::convert(w.len(), step!("number of wins")))
+    .collect::<Result<_, _>>()?)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    let number_of_wins = get_all_wins(parse_input(input)?)?;
+
+    Ok(arith::product(number_of_wins, step!("product of wins"))?)
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    Ok(get_all_wins(parse_input_joined(input)?)?[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 288);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 71503);
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use common::{arith, step, Int, Line, ParseError, SolveError};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Cards {
+    rank: i32,
+    card_1: i32,
+    card_2: i32,
+    card_3: i32,
+    card_4: i32,
+    card_5: i32
+}
+
+#[derive(Debug)]
+struct Hand {
+    cards: Cards,
+    bid: i32
+}
+
+const FIVE_OF_A_KIND: i32 =7;
+const FOUR_OF_A_KIND: i32 =6;
+const FULL_HOUSE: i32 = 5;
+const THREE_OF_A_KIND: i32 =4; 
+const TWO_PAIR: i32 = 3; 
+const ONE_PAIR: i32 = 2;
+const HIGH_CARD: i32 = 1;
+
+impl Cards {
+    fn parse(line: Line, cards: &str, jokers: bool) -> Result<Self, ParseError> {
+        let mut card_count = HashMap::new();
+        let mut parsed_cards = Vec::new();
+        let mut joker_count = 0;
+
+        for (card_idx, card) in cards.char_indices() {
+            if jokers && card == 'J' {
+                joker_count += 1;
+            } else {
+                card_count.entry(card).and_modify(|cc| *cc += 1).or_insert(1);
+            }
+            let parsed_card: i32 = match card {
+                'A' => 14,
+                'K' => 13,
+                'Q' => 12,
+                'J' if jokers => 1,
+                'J' => 11,
+                'T' => 10,
+                c => match c.to_digit(10) {
+                    Some(d) if d >= 2 => d,
+                    _ => return Err(line.error(&cards[card_idx..card_idx + c.len_utf8()], "a card"))
+                }
+            }.try_into().unwrap();
+
+            parsed_cards.push(parsed_card);
+        }
+
+        let max_card_count = *card_count.values().max().unwrap_or(&0);
+        let rank = match (max_card_count, joker_count) {
+                (3, 0) if card_count.len() == 2 => FULL_HOUSE,
+                (2, 0) if card_count.len() == 3 => TWO_PAIR,
+                (2 ,1) if card_count.len() == 2 => FULL_HOUSE,
+                
+                (a, b) if a + b == 5 => FIVE_OF_A_KIND,
+                (a, b) if a + b == 4 => FOUR_OF_A_KIND,
+                (a, b) if a + b == 3 => THREE_OF_A_KIND,
+                (a, b) if a + b == 2 => ONE_PAIR,
+                (a, b) if a + b == 1 => HIGH_CARD,
+
+                _ => return Err(line.error(cards, "five cards"))
+            
+        };
+
+        if parsed_cards.len() != 5 {
+            return Err(line.error(cards, "five cards"));
+        }
+
+        Ok(Cards {
+            rank,
+            card_1: parsed_cards[0],
+            card_2: parsed_cards[1],
+            card_3: parsed_cards[2],
+            card_4: parsed_cards[3],
+            card_5: parsed_cards[4]
+        })
+    }
+}
+
+
+fn parse_input(input: &str, jokers: bool) -> Result<Vec<Hand>, ParseError> {
+    let mut hands: Vec<Hand> = Vec::new();
+
+    for line in common::lines(input) {
+        let (cards, bid) = line.split_once(line.text, " ")?;
+        hands.push(Hand { cards: Cards::parse(line, cards, jokers)?, bid: line.number(bid)? });
+    }
+
+    Ok(hands)
+}
+
+fn get_winnings(mut input: Vec<Hand>) -> Result<Int, SolveError> {
+    input.sort_by(|k1, k2| k1.cards.cmp(&k2.cards));
+
+    let mut sum = 0;
+    for (idx, hand) in input.iter().enumerate() {
+        let rank: Int = arith::convert(idx + 1, step!("hand rank"))?;
+        sum = arith::add(sum, arith::mul(rank, Int::from(hand.bid), step!("hand winnings"))?, step!("total winnings"))?;
+    }
+
+    Ok(sum)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input, false).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    get_winnings(parse_input(input, false)?) // 251927063
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    get_winnings(parse_input(input, true)?) // 255632664
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 6440);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 5905);
+    }
+
+    fn rank(cards: &str, jokers: bool) -> i32 {
+        Cards::parse(Line { idx: 0, text: cards }, cards, jokers).unwrap().rank
+    }
+
+    #[test]
+    fn rank_table() {
+        assert_eq!(rank("AAAAA", false), FIVE_OF_A_KIND);
+        assert_eq!(rank("AA8AA", false), FOUR_OF_A_KIND);
+        assert_eq!(rank("23332", false), FULL_HOUSE);
+        assert_eq!(rank("TTT98", false), THREE_OF_A_KIND);
+        assert_eq!(rank("23432", false), TWO_PAIR);
+        assert_eq!(rank("A23A4", false), ONE_PAIR);
+        assert_eq!(rank("23456", false), HIGH_CARD);
+    }
+
+    #[test]
+    fn rank_table_with_jokers() {
+        assert_eq!(rank("JJJJJ", true), FIVE_OF_A_KIND);
+        assert_eq!(rank("QJJQ2", true), FOUR_OF_A_KIND);
+        assert_eq!(rank("2233J", true), FULL_HOUSE);
+        assert_eq!(rank("T55J5", true), FOUR_OF_A_KIND);
+        assert_eq!(rank("2345J", true), ONE_PAIR);
+        assert_eq!(rank("2235J", true), THREE_OF_A_KIND);
+    }
+
+    #[test]
+    fn jokers_are_weakest() {
+        let line = Line { idx: 0, text: "" };
+        assert!(Cards::parse(line, "JKKK2", true).unwrap() < Cards::parse(line, "QQQQ2", true).unwrap());
+        assert!(Cards::parse(line, "JJJJ2", false).unwrap() > Cards::parse(line, "TTTT2", false).unwrap());
+    }
+
+    #[test]
+    fn rejects_bad_cards() {
+        assert!(Cards::parse(Line { idx: 0, text: "1AAAA" }, "1AAAA", false).is_err());
+        assert!(Cards::parse(Line { idx: 0, text: "AAAA" }, "AAAA", false).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use regex::Regex;
+use common::{arith, step, Int, Line, ParseError, SolveError};
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right
+}
+
+impl Direction {
+    fn parse(line: Line) -> Result<Vec<Self>, ParseError> {
+        line.text.char_indices().map(
+            |(idx, c)| match c {
+                'L' => Ok(Self::Left),
+                'R' => Ok(Self::Right),
+                _ => Err(line.char_error(idx, "'L' or 'R'"))
+            }
+        ).collect()
+    }
+}
+
+struct Map {
+    directions: Vec<Direction>,
+    moves: HashMap<String, (String, String)>
+}
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let mut moves = HashMap::new();
+    let mut lines = common::lines(input);
+    let direction_line = lines.expect("directions")?;
+    let directions = Direction::parse(direction_line)?;
+    if directions.is_empty() {
+        return Err(direction_line.error(direction_line.text, "at least one 'L' or 'R'"));
+    }
+    lines.expect("an empty line")?.expect("")?;
+
+    let re = Regex::new(r"^([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)$").unwrap();
+    let mut targets = Vec::new();
+    for line in lines {
+        let Some(capture) = re.captures(line.text) else {
+            return Err(line.error(line.text, "'AAA = (BBB, CCC)'"));
+        };
+        let (_, [initial, left, right]) = capture.extract();
+        moves.insert(initial.to_owned(), (left.to_owned(), right.to_owned()));
+        targets.extend([(line, left), (line, right)]);
+    }
+
+    if let Some((line, target)) = targets.into_iter().find(|(_, target)| !moves.contains_key(*target)) {
+        return Err(line.error(target, "a node with its own line"));
+    }
+    Ok(Map { directions, moves })
+}
+
+struct Step<'a> {
+    next: &'a str
+}
+
+impl Map {
+    fn walk_one<'a>(&'a self, start: &'a str) -> Step<'a> {
+        let mut curr = start;
+        for next_direction in &self.directions {
+            let (next_left, next_right) = &self.moves[curr];
+
+            curr = match next_direction {
+                Direction::Left => next_left,
+                Direction::Right => next_right
+            };
+        }
+
+        Step { next: curr }
+    }
+
+    /// `None` if there is no AAA, or if the walk from it comes back to the same node at the same
+    /// point in the directions without having passed ZZZ.
+    fn walk_to_zzz(&self) -> Option<usize> {
+        let mut curr = self.moves.get_key_value("AAA")?.0.as_str();
+        let mut step = 0;
+        let direction_len = self.directions.len();
+    
+        loop {
+            if curr == "ZZZ" { break; }
+            if step >= direction_len * self.moves.len() { return None; }
+    
+            let next_direction = &self.directions[step % direction_len];
+            let (next_left, next_right) = &self.moves[curr];
+            curr = match next_direction {
+                Direction::Left => next_left,
+                Direction::Right => next_right
+            };
+    
+            step += 1;
+        }
+    
+        Some(step)
+    }
+
+    fn get_combined_moves(&self) -> HashMap<&str, Step<'_>> {
+        let mut combined_moves = HashMap::new();
+        self.moves.keys().for_each(
+            | start | 
+            { combined_moves.insert(&start[..], self.walk_one(start)); }
+        );
+
+        combined_moves
+    }
+}
+
+
+/// `None` if the walk goes round every node without ending on one that ends in Z.
+fn walk_to_end(start: &str, moves: &HashMap<&str, Step>) -> Option<Int> {
+    let mut curr = start;
+    let mut count = 0;
+
+    loop {
+        curr = moves[curr].next;
+        count += 1;
+
+        if curr.ends_with('Z') {
+            break;
+        }
+        if count >= moves.len() {
+            return None;
+        }
+    }
+
+    Int::try_from(count).ok()
+}
+
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, SolveError> {
+    parse_input(input)?.walk_to_zzz().ok_or(SolveError::NoSolution(step!("the walk from AAA never reaches ZZZ"))) //17873
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let map = parse_input(input)?;
+    let combined_moves = map.get_combined_moves();
+    let starts: Vec<&String> = map.moves.keys().filter(|k| k.ends_with('A')).collect();
+    let loops = starts.iter()
+        .map(|s| walk_to_end(s, &combined_moves))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(SolveError::NoSolution(step!("a walk from a node ending in A never reaches one ending in Z")))?;
+    let number_of_loops = arith::product(loops, step!("product of loop lengths"))?;
+    let step_length: Int = arith::convert(map.directions.len(), step!("number of directions"))?;
+
+    Ok(arith::mul(number_of_loops, step_length, step!("total steps"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(solve_part1(EXAMPLE_1).unwrap(), 2);
+        assert_eq!(solve_part1(EXAMPLE_2).unwrap(), 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE_3).unwrap(), 6);
+    }
+
+    #[test]
+    fn rejects_missing_directions_and_nodes() {
+        let error = parse("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = parse("L\n\nAAA = (ZZZ, QQQ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (3, 13, "QQQ"));
+    }
+
+    #[test]
+    fn no_way_to_zzz() {
+        let no_solution = Err(SolveError::NoSolution(step!("the walk from AAA never reaches ZZZ")));
+        assert_eq!(solve_part1("LL\n\nAAA = (BBB, AAA)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"), no_solution);
+        assert_eq!(solve_part1("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)"), no_solution);
+        assert_eq!(
+            solve_part2("L\n\n11A = (11B, 11B)\n11B = (11A, 11A)"),
+            Err(SolveError::NoSolution(step!("a walk from a node ending in A never reaches one ending in Z")))
+        );
+    }
+}
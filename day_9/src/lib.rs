@@ -0,0 +1,138 @@
+use common::{arith::{self, Overflow}, step, Int, ParseError, SolveError};
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Int>>, ParseError> {
+    let mut sequences = Vec::new();
+
+    for line in common::lines(input) {
+        if line.text.trim().is_empty() {
+            return Err(line.error(line.text, "a sequence of numbers"));
+        }
+        let numbers:Vec<Int> = line.text.split_whitespace().map(|n| line.number(n)).collect::<Result<_, _>>()?;
+        sequences.push(numbers);
+    }
+
+    Ok(sequences)
+}
+
+struct SequencElement {
+    differences: Vec<Int>
+}
+
+struct Sequence {
+    first: SequencElement,
+    last: SequencElement,
+}
+
+impl SequencElement {
+    fn next(&self) -> Result<Self, Overflow> {
+        let mut new_differences = Vec::new();
+        let mut total = 0;
+
+        for old_difference in &self.differences {
+            total = arith::add(total, *old_difference, step!("extrapolating forwards"))?;
+            new_differences.push(total);
+        }
+
+        Ok(SequencElement { differences: new_differences})
+    }
+
+    fn previous(&self) -> Result<Self, Overflow> {
+        let mut new_differences = Vec::new();
+        let mut total = 0;
+
+        for old_difference in &self.differences {
+            total = arith::sub(*old_difference, total, step!("extrapolating backwards"))?;
+            new_differences.push(total);
+        }
+
+        Ok(SequencElement { differences: new_differences})
+    }
+
+    fn value(&self) -> Int {
+        *self.differences.last().unwrap()
+    }
+}
+
+fn create_sequence(sequence: &[Int]) -> Result<Sequence, Overflow> {
+    // Seeded with a zero so that a sequence that's all zeros extrapolates to zero.
+    if sequence.iter().all(|e| *e == 0) {
+        return Ok(Sequence { first: SequencElement { differences : vec![0] } , last: SequencElement { differences : vec![0] } })
+    }
+
+    let mut differences = Vec::new();
+    let len = sequence.len();
+    for idx in 1..len {
+        differences.push(arith::sub(sequence[idx], sequence[idx-1], step!("differences"))?);
+    }
+
+    let mut parsed_sequence = create_sequence(&differences)?;
+
+    let last_element = *sequence.last().unwrap();
+    let first_element = sequence[0];
+    parsed_sequence.last.differences.push(last_element);
+    parsed_sequence.first.differences.push(first_element);
+
+    Ok(parsed_sequence)
+}
+
+fn create_sequences(input: &str) -> Result<Vec<Sequence>, SolveError> {
+    Ok(parse_input(input)?
+        .iter()
+        .map(|s| create_sequence(s))
+        .collect::<Result<_, _>>()?)
+}
+
+pub fn parse(input: &str) -> Result<(), ParseError> {
+    parse_input(input).map(|_| ())
+}
+
+pub fn solve_part1(input: &str) -> Result<Int, SolveError> {
+    let predictions = create_sequences(input)?
+        .iter()
+        .map(|s| s.last.next().map(|e| e.value()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arith::sum(predictions, step!("sum of predictions"))?) //1992273652
+}
+
+pub fn solve_part2(input: &str) -> Result<Int, SolveError> {
+    let predictions = create_sequences(input)?
+        .iter()
+        .map(|s| s.first.previous().map(|e| e.value()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(arith::sum(predictions, step!("sum of predictions"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(EXAMPLE).unwrap(), 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(EXAMPLE).unwrap(), 2);
+    }
+
+    #[test]
+    fn all_zeros() {
+        assert_eq!(solve_part1("0 0 0\n").unwrap(), 0);
+        assert_eq!(solve_part2("0 0 0\n").unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_a_blank_line() {
+        let error = parse("1 2 3\n\n4 5 6").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}